@@ -0,0 +1,231 @@
+use bech32::{FromBase32, ToBase32, Variant};
+
+use crate::{
+    error::Error, Network, Script, ScriptHashType, SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH,
+    SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+};
+
+/// The payload format type of the full address.
+const FULL_FORMAT: u8 = 0x00;
+/// The payload format type of the deprecated short address.
+const SHORT_FORMAT: u8 = 0x01;
+/// The payload format type of the deprecated full address with the `data`
+/// hash type.
+const FULL_DATA_FORMAT: u8 = 0x02;
+/// The payload format type of the deprecated full address with the `type`
+/// hash type.
+const FULL_TYPE_FORMAT: u8 = 0x04;
+
+/// The code hash indices of the short address, keyed by the type hash.
+const SHORT_CODE_HASH_INDICES: &[(crate::H256, u8)] = &[
+    (SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH, 0x00),
+    (SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH, 0x01),
+];
+
+impl Network {
+    /// The human-readable part of the addresses on this network.
+    pub fn address_hrp(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "ckb",
+            Self::Testnet => "ckt",
+        }
+    }
+}
+
+impl Script {
+    /// Encode this lock script as a full format bech32m address.
+    ///
+    /// Refer to RFC [CKB Address Format](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0021-ckb-address-format/0021-ckb-address-format.md).
+    pub fn to_address(&self, network: Network) -> Result<String, Error> {
+        let mut payload = Vec::with_capacity(34 + self.args.len());
+        payload.push(FULL_FORMAT);
+        payload.extend_from_slice(&self.code_hash.0);
+        payload.push(self.hash_type as u8);
+        payload.extend_from_slice(&self.args);
+
+        encode(network, &payload, Variant::Bech32m)
+    }
+
+    /// Encode this lock script as a deprecated short format bech32 address,
+    /// which only supports the secp256k1 blake160 sighash-all and
+    /// multisig-all locks with 20-byte `args`.
+    pub fn to_short_address(&self, network: Network) -> Result<String, Error> {
+        let index = SHORT_CODE_HASH_INDICES
+            .iter()
+            .find(|(code_hash, _)| *code_hash == self.code_hash)
+            .filter(|_| self.hash_type == ScriptHashType::Type && self.args.len() == 20)
+            .map(|(_, index)| *index)
+            .ok_or_else(|| Error::Address("no short address for the script".to_owned()))?;
+
+        let mut payload = vec![SHORT_FORMAT, index];
+        payload.extend_from_slice(&self.args);
+
+        encode(network, &payload, Variant::Bech32)
+    }
+}
+
+impl Script {
+    /// Decode the lock script from an address on either network.
+    ///
+    /// Both the full format and the deprecated short and full formats are
+    /// accepted. Malformed addresses and bad checksums are reported as
+    /// [Error::Address].
+    pub fn from_address(address: &str) -> Result<Script, Error> {
+        let invalid = |reason: &str| Error::Address(reason.to_owned());
+        let (hrp, data, variant) =
+            bech32::decode(address).map_err(|e| Error::Address(e.to_string()))?;
+
+        if hrp != Network::Mainnet.address_hrp() && hrp != Network::Testnet.address_hrp() {
+            return Err(invalid("unknown network prefix"));
+        }
+
+        let payload = Vec::<u8>::from_base32(&data).map_err(|e| Error::Address(e.to_string()))?;
+        let (format, payload) = payload
+            .split_first()
+            .ok_or_else(|| invalid("empty payload"))?;
+        let expected_variant = match *format {
+            FULL_FORMAT => Variant::Bech32m,
+            _ => Variant::Bech32,
+        };
+        if variant != expected_variant {
+            return Err(invalid("wrong checksum variant for the format"));
+        }
+
+        match *format {
+            FULL_FORMAT => {
+                if payload.len() < 33 {
+                    return Err(invalid("payload too short"));
+                }
+                let hash_type = match payload[32] {
+                    0 => ScriptHashType::Data,
+                    1 => ScriptHashType::Type,
+                    2 => ScriptHashType::Data1,
+                    _ => return Err(invalid("unknown hash type")),
+                };
+                full_script(&payload[..32], hash_type, &payload[33..])
+            }
+            FULL_DATA_FORMAT | FULL_TYPE_FORMAT => {
+                if payload.len() < 32 {
+                    return Err(invalid("payload too short"));
+                }
+                let hash_type = if *format == FULL_DATA_FORMAT {
+                    ScriptHashType::Data
+                } else {
+                    ScriptHashType::Type
+                };
+                full_script(&payload[..32], hash_type, &payload[32..])
+            }
+            SHORT_FORMAT => {
+                let (index, args) = payload
+                    .split_first()
+                    .ok_or_else(|| invalid("payload too short"))?;
+                let (code_hash, _) = SHORT_CODE_HASH_INDICES
+                    .iter()
+                    .find(|(_, i)| i == index)
+                    .ok_or_else(|| invalid("unknown short code hash index"))?;
+                if args.len() != 20 {
+                    return Err(invalid("short address args must be 20 bytes"));
+                }
+
+                Ok(Script {
+                    code_hash: code_hash.clone(),
+                    hash_type: ScriptHashType::Type,
+                    args:      args.to_vec().into(),
+                })
+            }
+            _ => Err(invalid("unknown format type")),
+        }
+    }
+}
+
+fn full_script(code_hash: &[u8], hash_type: ScriptHashType, args: &[u8]) -> Result<Script, Error> {
+    Ok(Script {
+        code_hash: code_hash.try_into()?,
+        hash_type,
+        args: args.to_vec().into(),
+    })
+}
+
+fn encode(network: Network, payload: &[u8], variant: Variant) -> Result<String, Error> {
+    bech32::encode(network.address_hrp(), payload.to_base32(), variant)
+        .map_err(|e| Error::Address(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphqlBytes;
+
+    fn secp256k1_lock() -> Script {
+        Script {
+            code_hash: SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            hash_type: ScriptHashType::Type,
+            args:      "0xb39bbc0b3673c7d36450bc14cfcdad2d559c6c64"
+                .parse::<GraphqlBytes>()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_to_address() {
+        let lock = secp256k1_lock();
+
+        assert_eq!(
+            lock.to_address(Network::Mainnet).unwrap(),
+            "ckb1qzda0cr08m85hc8jlnfp3zer7xulejywt49kt2rr0vthywaa50xwsqdnnw7qkdnnclfkg59uzn8umtfd2kwxceqxwquc4"
+        );
+        assert_eq!(
+            lock.to_address(Network::Testnet).unwrap(),
+            "ckt1qzda0cr08m85hc8jlnfp3zer7xulejywt49kt2rr0vthywaa50xwsqdnnw7qkdnnclfkg59uzn8umtfd2kwxceqgutnjd"
+        );
+    }
+
+    #[test]
+    fn test_to_short_address() {
+        let lock = secp256k1_lock();
+
+        assert_eq!(
+            lock.to_short_address(Network::Mainnet).unwrap(),
+            "ckb1qyqt8xaupvm8837nv3gtc9x0ekkj64vud3jqfwyw5v"
+        );
+        assert!(matches!(
+            Script::random().to_short_address(Network::Mainnet),
+            Err(Error::Address(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_address() {
+        let lock = secp256k1_lock();
+
+        for address in [
+            lock.to_address(Network::Mainnet).unwrap(),
+            lock.to_address(Network::Testnet).unwrap(),
+            lock.to_short_address(Network::Mainnet).unwrap(),
+        ] {
+            assert_eq!(Script::from_address(&address).unwrap(), lock);
+        }
+
+        let script = Script::random();
+        let address = script.to_address(Network::Mainnet).unwrap();
+        assert_eq!(Script::from_address(&address).unwrap(), script);
+    }
+
+    #[test]
+    fn test_from_corrupted_address() {
+        let address = secp256k1_lock().to_address(Network::Mainnet).unwrap();
+        let mut corrupted = address.into_bytes();
+        let last = corrupted.len() - 1;
+        corrupted[last] = if corrupted[last] == b'q' { b'p' } else { b'q' };
+        let corrupted = String::from_utf8(corrupted).unwrap();
+
+        assert!(matches!(
+            Script::from_address(&corrupted),
+            Err(Error::Address(_))
+        ));
+        assert!(matches!(
+            Script::from_address("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            Err(Error::Address(_))
+        ));
+    }
+}
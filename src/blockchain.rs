@@ -1 +1,743 @@
+use async_graphql::{ComplexObject, SimpleObject};
+use ckb_types::{core, packed, prelude::*, utilities::compact_to_difficulty};
 
+use crate::{
+    compact_to_target_saturating, error::Error, BlockNumber, Capacity, CompactTarget, EpochNumber,
+    EpochNumberWithFraction, GraphqlBytes, Nonce, ProposalShortId, Timestamp, TransactionView,
+    Uint256, Uint64, Version, H256,
+};
+
+/// The block header.
+///
+/// Refer to RFC [CKB Block Structure](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0027-block-structure/0027-block-structure.md).
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[graphql(complex)]
+pub struct Header {
+    /// The block version.
+    ///
+    /// It must equal to 0 now and is reserved for future upgrades.
+    pub version:           Version,
+    /// The block difficulty target.
+    ///
+    /// It can be converted to a 256-bit target. Miners must ensure the
+    /// Eaglesong of the header is within the target.
+    pub compact_target:    CompactTarget,
+    /// The block timestamp.
+    ///
+    /// It is a Unix timestamp in milliseconds (1 second = 1000 milliseconds).
+    pub timestamp:         Timestamp,
+    /// The consecutive block number starting from 0.
+    pub number:            BlockNumber,
+    /// The epoch information of this block.
+    ///
+    /// See [EpochNumberWithFraction](type.EpochNumberWithFraction.html) for
+    /// details.
+    pub epoch:             EpochNumberWithFraction,
+    /// The header hash of the parent block.
+    pub parent_hash:       H256,
+    /// The commitment to all the transactions in the block.
+    ///
+    /// It is a hash on two Merkle Tree roots:
+    ///
+    /// * The root of a CKB Merkle Tree, which items are the transaction hashes
+    ///   of all the transactions in the block.
+    /// * The root of a CKB Merkle Tree, but the items are the transaction
+    ///   witness hashes of all the transactions in the block.
+    pub transactions_root: H256,
+    /// The hash on `proposals` in the block body.
+    ///
+    /// It is all zeros when `proposals` is empty, or the hash on all the bytes
+    /// concatenated together.
+    pub proposals_hash:    H256,
+    /// The hash on `uncles` and extension in the block body.
+    pub extra_hash:        H256,
+    /// DAO fields.
+    ///
+    /// See RFC [Deposit and Withdraw in Nervos DAO](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#calculation).
+    pub dao:               GraphqlBytes,
+    /// Miner can modify this field to find a proper value such that the
+    /// Eaglesong of the header is within the target encoded from
+    /// `compact_target`.
+    pub nonce:             Nonce,
+    /// The header hash. It is also called the block hash.
+    ///
+    /// This field is derived from the other fields and is ignored when
+    /// converting back to `packed::Header`.
+    pub hash:              H256,
+}
+
+#[ComplexObject]
+impl Header {
+    /// The epoch number decoded from `epoch`.
+    async fn epoch_number(&self) -> EpochNumber {
+        EpochNumber::new(crate::epoch_number(&self.epoch))
+    }
+
+    /// The index of this block in its epoch, decoded from `epoch`.
+    async fn epoch_index(&self) -> Uint64 {
+        Uint64::new(crate::epoch_index(&self.epoch))
+    }
+
+    /// The number of blocks in the epoch, decoded from `epoch`.
+    async fn epoch_length(&self) -> Uint64 {
+        Uint64::new(crate::epoch_length(&self.epoch))
+    }
+
+    /// The 256-bit target expanded from `compact_target`.
+    ///
+    /// An overflowed compact target is expanded to the maximum target
+    /// `0xffff...ffff`.
+    async fn target(&self) -> Uint256 {
+        compact_to_target_saturating(&self.compact_target)
+    }
+
+    /// The block difficulty computed from `compact_target`.
+    async fn difficulty(&self) -> Uint256 {
+        compact_to_difficulty(self.compact_target.0).into()
+    }
+
+    /// The Nervos DAO statistics decoded from `dao`.
+    async fn dao_fields(&self) -> async_graphql::Result<DaoFields> {
+        Ok(decode_dao(&self.dao)?)
+    }
+}
+
+/// The Nervos DAO statistics packed in the header `dao` field.
+///
+/// See RFC [Deposit and Withdraw in Nervos DAO](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#calculation).
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct DaoFields {
+    /// The total issuance up to and including this block.
+    pub c:  Capacity,
+    /// The accumulated rate, starting from 10^16 in the genesis block.
+    pub ar: Uint64,
+    /// The total unissued secondary issuance up to and including this block.
+    pub s:  Capacity,
+    /// The total occupied capacities currently in the blockchain.
+    pub u:  Capacity,
+}
+
+/// Decode the 32-byte header `dao` field, which packs `C`, `AR`, `S` and `U`
+/// as little-endian 64-bit unsigned integers in this order.
+pub fn decode_dao(bytes: &GraphqlBytes) -> Result<DaoFields, Error> {
+    let bytes = bytes.to_array::<32>()?;
+    let field = |i: usize| {
+        let mut le = [0u8; 8];
+        le.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+        Uint64::new(u64::from_le_bytes(le))
+    };
+
+    Ok(DaoFields {
+        c:  field(0),
+        ar: field(1),
+        s:  field(2),
+        u:  field(3),
+    })
+}
+
+/// Computes `hash` with `calc_header_hash`, since the packed header doesn't
+/// carry it.
+impl From<packed::Header> for Header {
+    fn from(value: packed::Header) -> Self {
+        let raw = value.raw();
+
+        Self {
+            version:           raw.version().into(),
+            compact_target:    raw.compact_target().into(),
+            timestamp:         raw.timestamp().into(),
+            number:            raw.number().into(),
+            epoch:             raw.epoch().into(),
+            parent_hash:       raw.parent_hash().into(),
+            transactions_root: raw.transactions_root().into(),
+            proposals_hash:    raw.proposals_hash().into(),
+            extra_hash:        raw.extra_hash().into(),
+            dao:               GraphqlBytes(raw.dao().as_bytes()),
+            nonce:             value.nonce().into(),
+            hash:              value.calc_header_hash().into(),
+        }
+    }
+}
+
+/// Takes `hash` from the view, which computed it when it was built, so the
+/// header is not hashed again and `hash` is exactly the node's value.
+impl From<core::HeaderView> for Header {
+    fn from(value: core::HeaderView) -> Self {
+        let header = value.data();
+        let raw = header.raw();
+
+        Self {
+            version:           raw.version().into(),
+            compact_target:    raw.compact_target().into(),
+            timestamp:         raw.timestamp().into(),
+            number:            raw.number().into(),
+            epoch:             raw.epoch().into(),
+            parent_hash:       raw.parent_hash().into(),
+            transactions_root: raw.transactions_root().into(),
+            proposals_hash:    raw.proposals_hash().into(),
+            extra_hash:        raw.extra_hash().into(),
+            dao:               GraphqlBytes(raw.dao().as_bytes()),
+            nonce:             header.nonce().into(),
+            hash:              value.hash().into(),
+        }
+    }
+}
+
+/// Returns [Error::InvalidLength] if `dao` is not 32 bytes.
+impl TryFrom<Header> for packed::Header {
+    type Error = Error;
+
+    fn try_from(value: Header) -> Result<Self, Self::Error> {
+        let dao = H256::try_from(value.dao.as_ref())?;
+        let raw = packed::RawHeader::new_builder()
+            .version(value.version.into())
+            .compact_target(value.compact_target.into())
+            .timestamp(value.timestamp.into())
+            .number(value.number.into())
+            .epoch(value.epoch.into())
+            .parent_hash(value.parent_hash.0.pack())
+            .transactions_root(value.transactions_root.0.pack())
+            .proposals_hash(value.proposals_hash.0.pack())
+            .extra_hash(value.extra_hash.0.pack())
+            .dao(dao.0.pack())
+            .build();
+
+        Ok(packed::Header::new_builder()
+            .raw(raw)
+            .nonce(value.nonce.into())
+            .build())
+    }
+}
+
+/// The uncle block used as a parameter in the RPC.
+///
+/// The chain stores only the uncle block header and proposal IDs. The header
+/// ensures the block is covered by PoW and can pass the consensus rules on
+/// uncle blocks. Proposal IDs are there because a block can commit
+/// transactions proposed in an uncle.
+///
+/// A block B1 is considered to be the uncle of another block B2 if all the
+/// following conditions are met:
+///
+/// 1. They are in the same epoch, sharing the same difficulty;
+/// 2. B2 block number is larger than B1;
+/// 3. B1's parent is either B2's ancestor or an uncle embedded in B2 or any of
+///    B2's ancestors.
+/// 4. B2 is the first block in its chain to refer to B1.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UncleBlock {
+    /// The uncle block header.
+    pub header:    Header,
+    /// Proposal IDs in the uncle block body.
+    pub proposals: Vec<ProposalShortId>,
+}
+
+impl From<packed::UncleBlock> for UncleBlock {
+    fn from(value: packed::UncleBlock) -> Self {
+        Self {
+            header:    value.header().into(),
+            proposals: value.proposals().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<core::UncleBlockView> for UncleBlock {
+    fn from(value: core::UncleBlockView) -> Self {
+        Self {
+            header:    value.header().into(),
+            proposals: value
+                .data()
+                .proposals()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// Returns [Error::InvalidLength] if the header `dao` is not 32 bytes.
+impl TryFrom<UncleBlock> for packed::UncleBlock {
+    type Error = Error;
+
+    fn try_from(value: UncleBlock) -> Result<Self, Self::Error> {
+        Ok(Self::new_builder()
+            .header(value.header.try_into()?)
+            .proposals(
+                value
+                    .proposals
+                    .into_iter()
+                    .map(Into::into)
+                    .collect::<Vec<packed::ProposalShortId>>()
+                    .pack(),
+            )
+            .build())
+    }
+}
+
+/// The block.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Block {
+    /// The block header.
+    pub header:       Header,
+    /// The uncles blocks in the block body.
+    pub uncles:       Vec<UncleBlock>,
+    /// The transactions in the block body.
+    pub transactions: Vec<TransactionView>,
+    /// The proposal IDs in the block body.
+    pub proposals:    Vec<ProposalShortId>,
+    /// The block extension, which is only present in v1 blocks since the
+    /// 2021 hardfork.
+    ///
+    /// Its hash is committed in the header `extra_hash` together with the
+    /// uncles hash.
+    pub extension:    Option<GraphqlBytes>,
+}
+
+impl From<packed::Block> for Block {
+    fn from(value: packed::Block) -> Self {
+        Self {
+            header:       value.header().into(),
+            uncles:       value.uncles().into_iter().map(Into::into).collect(),
+            transactions: value.transactions().into_iter().map(Into::into).collect(),
+            proposals:    value.proposals().into_iter().map(Into::into).collect(),
+            extension:    value
+                .extension()
+                .map(|extension| GraphqlBytes(extension.unpack())),
+        }
+    }
+}
+
+impl From<core::BlockView> for Block {
+    fn from(value: core::BlockView) -> Self {
+        let data = value.data();
+
+        Self {
+            header:       value.header().into(),
+            uncles:       data.uncles().into_iter().map(Into::into).collect(),
+            transactions: value.transactions().into_iter().map(Into::into).collect(),
+            proposals:    data.proposals().into_iter().map(Into::into).collect(),
+            extension:    value
+                .extension()
+                .map(|extension| GraphqlBytes(extension.unpack())),
+        }
+    }
+}
+
+impl Block {
+    /// The serialized size of the transaction at `tx_index` divided by the
+    /// total serialized size of all the transactions in the block.
+    ///
+    /// Returns [Error::OutOfRange] if `tx_index` is not a transaction in the
+    /// block, which is always the case for an empty block.
+    pub fn transaction_size_fraction(&self, tx_index: usize) -> Result<f64, Error> {
+        let tx = self.transactions.get(tx_index).ok_or(Error::OutOfRange {
+            index: tx_index,
+            len:   self.transactions.len(),
+        })?;
+        let total = self
+            .transactions
+            .iter()
+            .map(|tx| tx.to_molecule_bytes().len())
+            .sum::<usize>();
+
+        Ok(tx.to_molecule_bytes().len() as f64 / total as f64)
+    }
+}
+
+/// Convert the transactions of `block` lazily, one at a time, instead of
+/// collecting them all like `Block::from`.
+pub fn transaction_views(block: &packed::Block) -> impl Iterator<Item = TransactionView> + '_ {
+    block.transactions().into_iter().map(Into::into)
+}
+
+/// Consensus information of an epoch.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Epoch {
+    /// Consecutive epoch number starting from 0.
+    pub number:         EpochNumber,
+    /// The block number of the first block in the epoch.
+    ///
+    /// It also equals the total count of blocks in all the epochs which epoch
+    /// number is less than this epoch.
+    pub start_number:   BlockNumber,
+    /// The number of blocks in this epoch.
+    pub length:         Uint64,
+    /// The difficulty target for any block in this epoch.
+    pub compact_target: CompactTarget,
+}
+
+impl From<core::EpochExt> for Epoch {
+    fn from(value: core::EpochExt) -> Self {
+        Self {
+            number:         EpochNumber::new(value.number()),
+            start_number:   BlockNumber::new(value.start_number()),
+            length:         Uint64::new(value.length()),
+            compact_target: CompactTarget::new(value.compact_target()),
+        }
+    }
+}
+
+/// Block rewards for miners.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MinerReward {
+    /// The primary base block reward allocated to miners.
+    pub primary:   Capacity,
+    /// The secondary base block reward allocated to miners.
+    pub secondary: Capacity,
+    /// The transaction fees that are rewarded to miners because the
+    /// transaction is committed in the block.
+    ///
+    /// Miners get 60% of the transaction fee for each transaction committed in
+    /// the block.
+    pub committed: Capacity,
+    /// The transaction fees that are rewarded to miners because the
+    /// transaction is proposed in the block or its uncles.
+    ///
+    /// Miners get 40% of the transaction fee for each transaction proposed in
+    /// the block and committed later in its active commit window.
+    pub proposal:  Capacity,
+}
+
+impl From<core::MinerReward> for MinerReward {
+    fn from(value: core::MinerReward) -> Self {
+        Self {
+            primary:   Capacity::new(value.primary.as_u64()),
+            secondary: Capacity::new(value.secondary.as_u64()),
+            committed: Capacity::new(value.committed.as_u64()),
+            proposal:  Capacity::new(value.proposal.as_u64()),
+        }
+    }
+}
+
+/// Block base rewards.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BlockIssuance {
+    /// The primary base rewards.
+    pub primary:   Capacity,
+    /// The secondary base rewards.
+    pub secondary: Capacity,
+}
+
+impl From<core::BlockIssuance> for BlockIssuance {
+    fn from(value: core::BlockIssuance) -> Self {
+        Self {
+            primary:   Capacity::new(value.primary.as_u64()),
+            secondary: Capacity::new(value.secondary.as_u64()),
+        }
+    }
+}
+
+/// Block Economic State.
+///
+/// It includes the rewards details and when it is finalized.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BlockEconomicState {
+    /// Block base rewards.
+    pub issuance:     BlockIssuance,
+    /// Block rewards for miners.
+    pub miner_reward: MinerReward,
+    /// The total fees of all transactions committed in the block.
+    pub txs_fee:      Capacity,
+    /// The block hash of the block which creates the rewards as cells in its
+    /// cellbase transaction.
+    pub finalized_at: H256,
+}
+
+impl From<core::BlockEconomicState> for BlockEconomicState {
+    fn from(value: core::BlockEconomicState) -> Self {
+        Self {
+            issuance:     value.issuance.into(),
+            miner_reward: value.miner_reward.into(),
+            txs_fee:      Capacity::new(value.txs_fee.as_u64()),
+            finalized_at: value.finalized_at.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Header {
+    pub fn random() -> Self {
+        let mut header = Self {
+            version:           Version::random(),
+            compact_target:    CompactTarget::random(),
+            timestamp:         Timestamp::random(),
+            number:            BlockNumber::random(),
+            epoch:             EpochNumberWithFraction::random(),
+            parent_hash:       H256::random(),
+            transactions_root: H256::random(),
+            proposals_hash:    H256::random(),
+            extra_hash:        H256::random(),
+            dao:               H256::random().0.to_vec().into(),
+            nonce:             Nonce::random(),
+            hash:              H256::default(),
+        };
+        header.hash = packed::Header::try_from(header.clone())
+            .unwrap()
+            .calc_header_hash()
+            .into();
+        header
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_object;
+
+    #[test]
+    fn test_header_round_trip() {
+        let header = Header::random();
+        let packed_header = packed::Header::try_from(header.clone()).unwrap();
+
+        assert_eq!(Header::from(packed_header.clone()), header);
+        assert_eq!(Header::from(packed_header.clone().into_view()), header);
+        assert_eq!(
+            packed::Header::try_from(Header::from(packed_header.clone()))
+                .unwrap()
+                .as_slice(),
+            packed_header.as_slice()
+        );
+        assert!(matches!(
+            packed::Header::try_from(Header::default()),
+            Err(Error::InvalidLength {
+                expected: 32,
+                actual:   0,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_header_hash_paths() {
+        let packed_header = packed::Header::try_from(Header::random()).unwrap();
+        let view = packed_header.clone().into_view();
+
+        let from_packed = Header::from(packed_header.clone());
+        let from_view = Header::from(view.clone());
+        assert_eq!(from_packed.hash, from_view.hash);
+        assert_eq!(from_view.hash, H256::from(view.hash()));
+        assert_eq!(
+            from_packed.hash,
+            H256::from(packed_header.calc_header_hash())
+        );
+    }
+
+    #[test]
+    fn test_uncle_block_round_trip() {
+        let uncle = UncleBlock {
+            header:    Header::random(),
+            proposals: vec![ProposalShortId::random(), ProposalShortId::random()],
+        };
+        let packed_uncle = packed::UncleBlock::try_from(uncle.clone()).unwrap();
+
+        assert_eq!(packed_uncle.proposals().len(), 2);
+        assert_eq!(UncleBlock::from(packed_uncle.clone()), uncle);
+        assert_eq!(UncleBlock::from(packed_uncle.into_view()), uncle);
+    }
+
+    #[test]
+    fn test_block_with_one_transaction() {
+        let header = Header::random();
+        let tx = TransactionView::random();
+        let block = packed::Block::new_builder()
+            .header(header.clone().try_into().unwrap())
+            .transactions(vec![packed::Transaction::from(tx.clone())].pack())
+            .build();
+
+        let expected = Block {
+            header,
+            uncles: Vec::new(),
+            transactions: vec![tx],
+            proposals: Vec::new(),
+            extension: None,
+        };
+        assert_eq!(Block::from(block.clone()), expected);
+        // `into_view` would reset the random merkle roots in the header.
+        assert_eq!(
+            Block::from(block.into_view_without_reset_header()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_transaction_size_fraction() {
+        let mut block = Block::default();
+        assert!(matches!(
+            block.transaction_size_fraction(0),
+            Err(Error::OutOfRange { index: 0, len: 0 })
+        ));
+
+        block.transactions = vec![TransactionView::random(), TransactionView::default()];
+        let first = block.transaction_size_fraction(0).unwrap();
+        let second = block.transaction_size_fraction(1).unwrap();
+
+        assert!(first > second);
+        assert!((first + second - 1.0).abs() < 1e-9);
+        assert!(matches!(
+            block.transaction_size_fraction(2),
+            Err(Error::OutOfRange { index: 2, len: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_block_extension() {
+        let extension = GraphqlBytes::random();
+        let block = packed::BlockV1::new_builder()
+            .header(Header::random().try_into().unwrap())
+            .extension(extension.0.pack())
+            .build()
+            .as_v0();
+
+        assert_eq!(
+            Block::from(block.clone()).extension,
+            Some(extension.clone())
+        );
+        assert_eq!(Block::from(block.into_view()).extension, Some(extension));
+
+        let block = packed::Block::new_builder()
+            .header(Header::random().try_into().unwrap())
+            .build();
+        assert_eq!(Block::from(block.clone()).extension, None);
+        assert_eq!(Block::from(block.into_view()).extension, None);
+    }
+
+    #[test]
+    fn test_transaction_views() {
+        let transactions = vec![TransactionView::random(), TransactionView::random()];
+        let block = packed::Block::new_builder()
+            .header(Header::random().try_into().unwrap())
+            .transactions(
+                transactions
+                    .iter()
+                    .cloned()
+                    .map(packed::Transaction::from)
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .build();
+
+        let lazy = transaction_views(&block).collect::<Vec<_>>();
+        assert_eq!(lazy.len(), 2);
+        assert_eq!(lazy, Block::from(&block).transactions);
+        assert!(lazy
+            .iter()
+            .zip(&transactions)
+            .all(|(a, b)| a.hash == b.hash));
+    }
+
+    #[test]
+    fn test_epoch() {
+        let epoch = core::EpochExt::new_builder()
+            .number(50)
+            .start_number(11000)
+            .length(1000)
+            .compact_target(0x1d00ffff)
+            .build();
+
+        assert_eq!(Epoch::from(epoch), Epoch {
+            number:         EpochNumber::new(50),
+            start_number:   BlockNumber::new(11000),
+            length:         Uint64::new(1000),
+            compact_target: CompactTarget::new(0x1d00ffff),
+        });
+    }
+
+    #[test]
+    fn test_block_economic_state() {
+        let shannons = core::Capacity::shannons;
+        let finalized_at = H256::random();
+        let state = core::BlockEconomicState {
+            issuance:     core::BlockIssuance {
+                primary:   shannons(1),
+                secondary: shannons(2),
+            },
+            miner_reward: core::MinerReward {
+                primary:   shannons(3),
+                secondary: shannons(4),
+                committed: shannons(5),
+                proposal:  shannons(6),
+            },
+            txs_fee:      shannons(7),
+            finalized_at: finalized_at.0.pack(),
+        };
+
+        assert_eq!(BlockEconomicState::from(state), BlockEconomicState {
+            issuance: BlockIssuance {
+                primary:   Capacity::new(1),
+                secondary: Capacity::new(2),
+            },
+            miner_reward: MinerReward {
+                primary:   Capacity::new(3),
+                secondary: Capacity::new(4),
+                committed: Capacity::new(5),
+                proposal:  Capacity::new(6),
+            },
+            txs_fee: Capacity::new(7),
+            finalized_at,
+        });
+    }
+
+    #[tokio::test]
+    async fn test_header_pow_fields() {
+        let header = Header {
+            compact_target: CompactTarget::new(0x1d00ffff),
+            ..Default::default()
+        };
+        let difficulty = Uint256::from(compact_to_difficulty(0x1d00ffff));
+
+        assert_eq!(
+            query_object(header, "target difficulty").await,
+            serde_json::json!({
+                "target": "0xffff0000000000000000000000000000000000000000000000000000",
+                "difficulty": crate::hex::hex_be_uint(difficulty.0),
+            })
+        );
+
+        let header = Header {
+            compact_target: CompactTarget::new(0x21010000),
+            ..Default::default()
+        };
+        assert_eq!(
+            query_object(header, "target").await["target"],
+            crate::hex::hex_be_uint(crate::MAX_TARGET.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decode_dao() {
+        // The dao field of the mainnet genesis block.
+        let dao = "0x8874337e541ea12e0000c16ff286230029bfa3320800000000710b00c0fefe06"
+            .parse::<GraphqlBytes>()
+            .unwrap();
+        let expected = DaoFields {
+            c:  Capacity::new(3_360_000_145_238_488_200),
+            ar: Uint64::new(10_000_000_000_000_000),
+            s:  Capacity::new(35_209_330_473),
+            u:  Capacity::new(504_120_308_900_000_000),
+        };
+        assert_eq!(decode_dao(&dao).unwrap(), expected);
+
+        let header = Header {
+            dao,
+            ..Default::default()
+        };
+        assert_eq!(
+            query_object(header, "daoFields { c ar s u }").await,
+            serde_json::json!({
+                "daoFields": {
+                    "c": "0x2ea11e547e337488",
+                    "ar": "0x2386f26fc10000",
+                    "s": "0x832a3bf29",
+                    "u": "0x6fefec0000b7100",
+                },
+            })
+        );
+
+        assert!(matches!(
+            decode_dao(&GraphqlBytes::from(vec![0u8; 31])),
+            Err(Error::InvalidLength {
+                expected: 32,
+                actual:   31,
+            })
+        ));
+        assert!(decode_dao(&Header::default().dao).is_err());
+    }
+}
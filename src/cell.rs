@@ -1,9 +1,19 @@
-use std::fmt::{Display, Error, Formatter};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
-use async_graphql::{Enum, SimpleObject};
+use async_graphql::{ComplexObject, Enum, SimpleObject};
 use ckb_types::{packed, prelude::*};
 
-use crate::{Capacity, GraphqlBytes, Uint32, Uint64, H256};
+use crate::{
+    error::Error, Capacity, GraphqlBytes, Network, SinceFields, SinceValue, Uint32, Uint64, H160,
+    H256, MAINNET_SECP256K1_BLAKE160_DEP, MAINNET_SUDT_TYPE_HASH,
+    SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH, SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+    TESTNET_SECP256K1_BLAKE160_DEP, TESTNET_SUDT_TYPE_HASH,
+};
+
+/// The capacity in Shannons that a single byte of storage occupies.
+pub(crate) const BYTE_SHANNONS: u64 = 100_000_000;
 
 /// Specifies how the script `code_hash` is used to match the script code and
 /// how to run the code.
@@ -15,6 +25,9 @@ use crate::{Capacity, GraphqlBytes, Uint32, Uint64, H256};
 /// in the RFC *CKB Transaction Structure*.
 #[derive(Enum, Default, Copy, Clone, Debug, Hash, PartialEq, Eq)]
 #[graphql(remote = "ckb_types::core::ScriptHashType")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ScriptHashType {
     #[default]
     /// Type "data" matches script code via cell data hash, and run the script
@@ -28,7 +41,7 @@ pub enum ScriptHashType {
 }
 
 impl Display for ScriptHashType {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
             Self::Data => write!(f, "data"),
             Self::Type => write!(f, "type"),
@@ -37,6 +50,46 @@ impl Display for ScriptHashType {
     }
 }
 
+impl FromStr for ScriptHashType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "data" => Ok(Self::Data),
+            "type" => Ok(Self::Type),
+            "data1" => Ok(Self::Data1),
+            _ => Err(Error::InvalidScriptHashType(s.to_owned())),
+        }
+    }
+}
+
+/// A [ScriptHashType] sent as its lowercase string, such as `"data1"`, for
+/// schemas modeling the hash type as a string instead of the enum.
+#[derive(Default, Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ScriptHashTypeString(pub ScriptHashType);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for ScriptHashTypeString {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        if let async_graphql::Value::String(value) = &value {
+            return ScriptHashType::from_str(value)
+                .map(Self)
+                .map_err(async_graphql::InputValueError::custom);
+        }
+        Err(async_graphql::InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(self.0.to_string())
+    }
+}
+
+impl From<ScriptHashTypeString> for ScriptHashType {
+    fn from(value: ScriptHashTypeString) -> Self {
+        value.0
+    }
+}
+
 impl From<packed::Byte> for ScriptHashType {
     fn from(value: packed::Byte) -> Self {
         match value.as_slice()[0] {
@@ -48,8 +101,69 @@ impl From<packed::Byte> for ScriptHashType {
     }
 }
 
+#[cfg(test)]
+impl ScriptHashType {
+    pub fn random() -> Self {
+        match rand::random::<u8>() % 3 {
+            0 => Self::Data,
+            1 => Self::Type,
+            _ => Self::Data1,
+        }
+    }
+}
+
+/// A well-known system script, matched by the code hash and the hash type.
+struct WellKnownScript {
+    name:      &'static str,
+    /// `None` for scripts deployed in the genesis block, which share the
+    /// type hash on the mainnet and the testnet.
+    network:   Option<Network>,
+    code_hash: H256,
+    hash_type: ScriptHashType,
+    /// The required `args` length, or `None` if it may vary.
+    args_len:  Option<usize>,
+}
+
+/// The registry of well-known system scripts.
+const WELL_KNOWN_SCRIPTS: &[WellKnownScript] = &[
+    WellKnownScript {
+        name:      "secp256k1_blake160_sighash_all",
+        network:   None,
+        code_hash: SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+        hash_type: ScriptHashType::Type,
+        args_len:  Some(20),
+    },
+    WellKnownScript {
+        name:      "secp256k1_blake160_multisig_all",
+        network:   None,
+        code_hash: SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH,
+        hash_type: ScriptHashType::Type,
+        args_len:  None,
+    },
+    WellKnownScript {
+        name:      "sudt",
+        network:   Some(Network::Mainnet),
+        code_hash: MAINNET_SUDT_TYPE_HASH,
+        hash_type: ScriptHashType::Type,
+        args_len:  None,
+    },
+    WellKnownScript {
+        name:      "sudt",
+        network:   Some(Network::Testnet),
+        code_hash: TESTNET_SUDT_TYPE_HASH,
+        hash_type: ScriptHashType::Type,
+        args_len:  None,
+    },
+];
+
 /// Describes the lock script and type script for a cell.
+///
+/// The derived `Default` is an all-zero script which matches no deployed
+/// code, so prefer [Script::new] outside of tests.
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[graphql(complex)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Script {
     /// The hash used to match the script code.
     pub code_hash: H256,
@@ -59,6 +173,23 @@ pub struct Script {
     pub args:      GraphqlBytes,
 }
 
+#[ComplexObject]
+impl Script {
+    /// The name of the well-known system script matched by `code_hash` and
+    /// `hash_type`, such as `secp256k1_blake160_sighash_all`.
+    #[graphql(name = "wellKnownName")]
+    async fn resolve_well_known_name(&self) -> Option<&'static str> {
+        self.well_known_name()
+    }
+
+    /// The identifier `<code_hash>-<hash_type>` shared by all the scripts
+    /// running the same code, such as `0x9bd7...cce8-type`.
+    #[graphql(name = "scriptId")]
+    async fn resolve_script_id(&self) -> String {
+        self.script_id()
+    }
+}
+
 impl From<packed::Script> for Script {
     fn from(value: packed::Script) -> Self {
         Self {
@@ -79,8 +210,161 @@ impl From<Script> for packed::Script {
     }
 }
 
+impl Script {
+    /// Create a script from its code hash, hash type and args.
+    pub fn new(code_hash: H256, hash_type: ScriptHashType, args: GraphqlBytes) -> Self {
+        Self {
+            code_hash,
+            hash_type,
+            args,
+        }
+    }
+
+    /// Serialize the script in the molecule format.
+    pub fn to_molecule_bytes(&self) -> Vec<u8> {
+        packed::Script::from(self.clone()).as_slice().to_vec()
+    }
+
+    /// Deserialize a script from the molecule format.
+    pub fn from_molecule_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(packed::Script::from_slice(bytes)?.into())
+    }
+
+    /// Check the `args` length if this is a well-known script with fixed
+    /// length `args`. Unknown scripts always pass.
+    pub fn validate(&self) -> Result<(), Error> {
+        let rule = self
+            .find_well_known()
+            .and_then(|script| Some((script.name, script.args_len?)));
+
+        if let Some((name, expected)) = rule {
+            if self.args.0.len() != expected {
+                return Err(Error::InvalidScriptArgs {
+                    name,
+                    expected,
+                    actual: self.args.0.len(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The name of the well-known system script matched by `code_hash` and
+    /// `hash_type`, or `None` for unknown scripts.
+    pub fn well_known_name(&self) -> Option<&'static str> {
+        self.find_well_known().map(|script| script.name)
+    }
+
+    /// The network of the well-known system script matched by `code_hash` and
+    /// `hash_type`.
+    ///
+    /// It is `None` for unknown scripts and for scripts which have the same
+    /// code hash on both networks, such as the secp256k1 locks.
+    pub fn network_hint(&self) -> Option<Network> {
+        self.find_well_known().and_then(|script| script.network)
+    }
+
+    fn find_well_known(&self) -> Option<&'static WellKnownScript> {
+        WELL_KNOWN_SCRIPTS
+            .iter()
+            .find(|script| {
+                script.code_hash == self.code_hash && script.hash_type == self.hash_type
+            })
+    }
+
+    /// The identifier combining `code_hash` and `hash_type`, which groups
+    /// scripts by their code regardless of `args`.
+    pub fn script_id(&self) -> String {
+        format!(
+            "{}-{}",
+            crate::hex::hex_encode(&self.code_hash),
+            self.hash_type
+        )
+    }
+
+    /// Whether the script code is the default secp256k1 blake160 sighash-all
+    /// lock, regardless of `args`.
+    pub fn is_default_lock(&self) -> bool {
+        self.code_hash == SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH
+            && self.hash_type == ScriptHashType::Type
+    }
+
+    /// The number of bytes this script occupies in a cell, which is the sum of
+    /// the `code_hash`, `hash_type` and `args` lengths.
+    pub fn occupied_bytes(&self) -> usize {
+        self.code_hash.0.len() + 1 + self.args.0.len()
+    }
+
+    /// The capacity this script occupies in a cell.
+    pub fn occupied_capacity(&self) -> Capacity {
+        Capacity::new(self.occupied_bytes() as u64 * BYTE_SHANNONS)
+    }
+
+    /// Whether this is the default secp256k1 blake160 sighash-all lock with a
+    /// 20-byte public key hash as `args`.
+    pub fn is_secp256k1_blake160(&self, network: Network) -> bool {
+        self.code_hash == network.secp256k1_blake160_code_hash()
+            && self.hash_type == ScriptHashType::Type
+            && self.args.0.len() == 20
+    }
+
+    /// Whether this is the anyone-can-pay lock, whose `args` is a 20-byte
+    /// public key hash followed by up to two minimum amount bytes.
+    ///
+    /// See RFC [Anyone-Can-Pay Lock](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0026-anyone-can-pay/0026-anyone-can-pay.md).
+    pub fn is_acp(&self, network: Network) -> bool {
+        self.code_hash == network.acp_code_hash()
+            && self.hash_type == ScriptHashType::Type
+            && (20..=22).contains(&self.args.0.len())
+    }
+
+    /// Parse the optional minimum CKB and minimum UDT amounts from the
+    /// anyone-can-pay lock `args`.
+    ///
+    /// Each minimum is an exponent `x` meaning the minimal transfer amount is
+    /// `10^x` Shannons or UDT base units. Returns `None` if the script is not
+    /// an anyone-can-pay lock on `network`.
+    pub fn acp_minimums(&self, network: Network) -> Option<(Option<u8>, Option<u8>)> {
+        if !self.is_acp(network) {
+            return None;
+        }
+
+        match self.args.0.len() {
+            20 => Some((None, None)),
+            21 => Some((Some(self.args.0[20]), None)),
+            22 => Some((Some(self.args.0[20]), Some(self.args.0[21]))),
+            _ => None,
+        }
+    }
+
+    /// Interpret the first 20 bytes of `args` as a blake160 hash. Returns
+    /// `None` if `args` is shorter than 20 bytes.
+    pub fn args_as_h160(&self) -> Option<H160> {
+        let mut array = [0u8; 20];
+        array.copy_from_slice(self.args.0.get(..20)?);
+        Some(H160::new(array))
+    }
+}
+
+#[cfg(test)]
+impl Script {
+    pub fn random() -> Self {
+        Self {
+            code_hash: H256::random(),
+            hash_type: ScriptHashType::random(),
+            args:      GraphqlBytes::random(),
+        }
+    }
+}
+
 /// The fields of an output cell except the cell data.
+///
+/// The derived `Default` has zero capacity and the all-zero lock script, so
+/// prefer [CellOutput::new] outside of tests.
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CellOutput {
     /// The cell capacity.
     ///
@@ -91,6 +375,7 @@ pub struct CellOutput {
     /// The lock script.
     pub lock:     Script,
     /// The optional type script.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     pub type_:    Option<Script>,
 }
 
@@ -114,8 +399,140 @@ impl From<CellOutput> for packed::CellOutput {
     }
 }
 
+impl CellOutput {
+    /// Create a cell output with the capacity, lock script and optional type script.
+    pub fn new(capacity: Capacity, lock: Script, type_: Option<Script>) -> Self {
+        Self {
+            capacity,
+            lock,
+            type_,
+        }
+    }
+
+    /// Serialize the cell output in the molecule format.
+    pub fn to_molecule_bytes(&self) -> Vec<u8> {
+        packed::CellOutput::from(self.clone()).as_slice().to_vec()
+    }
+
+    /// Deserialize a cell output from the molecule format.
+    pub fn from_molecule_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(packed::CellOutput::from_slice(bytes)?.into())
+    }
+
+    /// The number of bytes the optional type script occupies in this cell, or
+    /// 0 if there is no type script.
+    ///
+    /// This is the storage, and so the capacity in CKBytes, saved by omitting
+    /// the type script.
+    pub fn type_script_size(&self) -> usize {
+        self.type_.as_ref().map_or(0, Script::occupied_bytes)
+    }
+
+    /// The capacity left after the [occupied
+    /// capacity](#method.occupied_capacity) of this cell with `data_len` bytes
+    /// of data. A negative margin means the cell is underfunded.
+    ///
+    /// The margin is computed in `i128`, which cannot overflow even when the
+    /// occupied capacity exceeds `u64`.
+    pub fn capacity_margin(&self, data_len: usize) -> i128 {
+        let occupied_bytes = 8
+            + self.lock.occupied_bytes() as i128
+            + self.type_script_size() as i128
+            + data_len as i128;
+
+        self.capacity.0 as i128 - occupied_bytes * BYTE_SHANNONS as i128
+    }
+
+    /// The capacity this cell occupies with `data_len` bytes of data, which
+    /// covers the 8-byte capacity field, the scripts and the data.
+    pub fn occupied_capacity(&self, data_len: usize) -> Result<Capacity, Error> {
+        (8 + self.lock.occupied_bytes() as u64 + self.type_script_size() as u64)
+            .checked_add(data_len as u64)
+            .and_then(|bytes| bytes.checked_mul(BYTE_SHANNONS))
+            .map(Capacity::new)
+            .ok_or(Error::CapacityOverflow)
+    }
+
+    /// Whether `capacity` covers the [occupied
+    /// capacity](#method.occupied_capacity) with `data_len` bytes of data.
+    pub fn is_capacity_sufficient(&self, data_len: usize) -> bool {
+        self.occupied_capacity(data_len)
+            .is_ok_and(|occupied| self.capacity.0 >= occupied.0)
+    }
+}
+
+/// The minimal capacity of an output cell with `lock`, no type script and
+/// empty data, which is the 8-byte capacity field plus the lock script.
+pub fn min_capacity_for_lock(lock: &Script) -> Capacity {
+    Capacity::new(8 * BYTE_SHANNONS + lock.occupied_capacity().0)
+}
+
+/// The capacity in CKB, which is lossy for large values and only meant for
+/// display.
+pub fn capacity_as_ckb(capacity: &Capacity) -> f64 {
+    capacity.0 as f64 / BYTE_SHANNONS as f64
+}
+
+/// The capacity in CKB, or [Error::PrecisionLoss] if converting it back does
+/// not give the same shannons.
+pub fn capacity_as_ckb_checked(capacity: &Capacity) -> Result<f64, Error> {
+    let ckb = capacity_as_ckb(capacity);
+
+    if (ckb * BYTE_SHANNONS as f64).round() as u64 != capacity.0 {
+        return Err(Error::PrecisionLoss(capacity.0));
+    }
+
+    Ok(ckb)
+}
+
+#[cfg(test)]
+impl CellOutput {
+    pub fn random() -> Self {
+        Self {
+            capacity: Capacity::random(),
+            lock:     Script::random(),
+            type_:    rand::random::<bool>().then(Script::random),
+        }
+    }
+}
+
+/// An output cell together with its data.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[graphql(complex)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellOutputWithData {
+    /// The output cell.
+    pub output: CellOutput,
+    /// The cell data.
+    pub data:   GraphqlBytes,
+}
+
+#[ComplexObject]
+impl CellOutputWithData {
+    /// The hash of the cell data, which scripts with hash type "data" or
+    /// "data1" match against. It is all zeros for empty data.
+    async fn data_hash(&self) -> H256 {
+        self.calc_data_hash()
+    }
+
+    /// The length of the cell data in bytes.
+    async fn data_length(&self) -> Uint32 {
+        Uint32::new(self.data.len() as u32)
+    }
+}
+
+impl CellOutputWithData {
+    /// Compute the data hash, which is the CKB blake2b hash of `data`, or all
+    /// zeros if `data` is empty.
+    pub fn calc_data_hash(&self) -> H256 {
+        packed::CellOutput::calc_data_hash(&self.data.0).into()
+    }
+}
+
 /// Reference to a cell via transaction hash and output index.
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OutPoint {
     /// Transaction hash in which the cell is an output.
     pub tx_hash: H256,
@@ -141,8 +558,76 @@ impl From<OutPoint> for packed::OutPoint {
     }
 }
 
+impl OutPoint {
+    /// Whether the index is the `0xffffffff` sentinel used by the null out
+    /// point of a cellbase input.
+    pub fn is_cellbase_index(&self) -> bool {
+        self.index.0 == u32::MAX
+    }
+
+    /// Whether this is the null out point referenced by the cellbase input.
+    pub fn is_null(&self) -> bool {
+        self.tx_hash == H256::default() && self.is_cellbase_index()
+    }
+}
+
+/// Display the out point as `tx_hash:index`.
+///
+/// The null out point is displayed as `null`, and the sentinel index
+/// `0xffffffff` is displayed in hex.
+impl Display for OutPoint {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.is_null() {
+            write!(f, "null")
+        } else if self.is_cellbase_index() {
+            write!(
+                f,
+                "{}:{}",
+                crate::hex::hex_encode(&self.tx_hash),
+                crate::hex::hex_uint(self.index.0)
+            )
+        } else {
+            write!(
+                f,
+                "{}:{}",
+                crate::hex::hex_encode(&self.tx_hash),
+                self.index.0
+            )
+        }
+    }
+}
+
+/// Parse the member out points from the cell data of a dep group, which is a
+/// molecule encoded `OutPointVec`.
+pub fn parse_dep_group(data: &GraphqlBytes) -> Result<Vec<OutPoint>, Error> {
+    Ok(packed::OutPointVec::from_slice(data)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Remove the duplicated out points, keeping the first occurrence of each in
+/// the original order.
+pub fn dedup_out_points(out_points: &mut Vec<OutPoint>) {
+    let mut seen = HashSet::new();
+    out_points.retain(|out_point| seen.insert(out_point.clone()));
+}
+
+#[cfg(test)]
+impl OutPoint {
+    pub fn random() -> Self {
+        Self {
+            tx_hash: H256::random(),
+            index:   Uint32::random(),
+        }
+    }
+}
+
 /// The input cell of a transaction.
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[graphql(complex)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CellInput {
     /// Restrict when the transaction can be committed into the chain.
     ///
@@ -152,6 +637,29 @@ pub struct CellInput {
     pub previous_output: OutPoint,
 }
 
+impl CellInput {
+    /// Decode the `since` field.
+    pub fn since_value(&self) -> SinceValue {
+        SinceValue::decode(self.since.0)
+    }
+
+    /// Replace the `since` field with the encoded `value`.
+    pub fn with_since_value(mut self, value: SinceValue) -> Self {
+        self.since = Uint64::new(value.encode());
+        self
+    }
+}
+
+#[ComplexObject]
+impl CellInput {
+    /// The decoded `since` field, or null when it sets reserved bits or uses
+    /// an unknown metric.
+    #[graphql(name = "sinceValue")]
+    async fn resolve_since_value(&self) -> Option<SinceFields> {
+        self.since_value().fields()
+    }
+}
+
 impl From<packed::CellInput> for CellInput {
     fn from(value: packed::CellInput) -> Self {
         Self {
@@ -170,9 +678,22 @@ impl From<CellInput> for packed::CellInput {
     }
 }
 
+#[cfg(test)]
+impl CellInput {
+    pub fn random() -> Self {
+        Self {
+            since:           Uint64::random(),
+            previous_output: OutPoint::random(),
+        }
+    }
+}
+
 /// The dep cell type. Allowed values: "code" and "dep_group".
 #[derive(Enum, Default, Copy, Clone, Debug, Hash, PartialEq, Eq)]
 #[graphql(remote = "ckb_types::core::DepType")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DepType {
     /// Type "code".
     ///
@@ -199,8 +720,21 @@ impl From<packed::Byte> for DepType {
     }
 }
 
+#[cfg(test)]
+impl DepType {
+    pub fn random() -> Self {
+        if rand::random() {
+            Self::Code
+        } else {
+            Self::DepGroup
+        }
+    }
+}
+
 /// The cell dependency of a transaction.
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CellDep {
     /// Reference to the cell.
     pub out_point: OutPoint,
@@ -225,3 +759,519 @@ impl From<CellDep> for packed::CellDep {
             .build()
     }
 }
+
+impl CellDep {
+    /// Whether this dep references one of the system script cells deployed in
+    /// the genesis block of `network`.
+    pub fn is_system_dep(&self, network: Network) -> bool {
+        network
+            .system_deps()
+            .iter()
+            .any(|dep| dep.out_point == self.out_point)
+    }
+
+    /// The dep group of the default secp256k1 blake160 sighash-all lock
+    /// deployed in the genesis block of `network`.
+    pub fn secp256k1_blake160(network: Network) -> CellDep {
+        match network {
+            Network::Mainnet => MAINNET_SECP256K1_BLAKE160_DEP,
+            Network::Testnet => TESTNET_SECP256K1_BLAKE160_DEP,
+        }
+    }
+}
+
+#[cfg(test)]
+impl CellDep {
+    pub fn random() -> Self {
+        Self {
+            out_point: OutPoint::random(),
+            dep_type:  DepType::random(),
+        }
+    }
+}
+
+/// The status of a cell referenced by an out point.
+#[derive(Enum, Default, Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CellStatus {
+    /// The cell exists and has not been spent.
+    Live,
+    /// The cell exists but has been spent.
+    Dead,
+    /// The cell is unknown to the node.
+    #[default]
+    Unknown,
+}
+
+/// A cell with its data.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellInfo {
+    /// The cell fields except the cell data.
+    pub output: CellOutput,
+    /// The cell data.
+    pub data:   GraphqlBytes,
+}
+
+/// The cell referenced by an out point together with its status.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellWithStatus {
+    /// The cell, which is only present when the cell is live.
+    pub cell:   Option<CellInfo>,
+    /// The status of the cell.
+    pub status: CellStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_object;
+
+    #[test]
+    fn test_molecule_bytes() {
+        let script = Script::random();
+        let bytes = script.to_molecule_bytes();
+        assert_eq!(Script::from_molecule_bytes(&bytes).unwrap(), script);
+        assert!(matches!(
+            Script::from_molecule_bytes(&bytes[1..]),
+            Err(Error::Molecule(_))
+        ));
+
+        let output = CellOutput::random();
+        let bytes = output.to_molecule_bytes();
+        assert_eq!(CellOutput::from_molecule_bytes(&bytes).unwrap(), output);
+    }
+
+    #[test]
+    fn test_data_hash() {
+        let mut cell = CellOutputWithData::default();
+        assert_eq!(cell.calc_data_hash(), H256::default());
+
+        cell.data = b"hello world".to_vec().into();
+        assert_eq!(
+            cell.calc_data_hash(),
+            H256::from_str("0x3376b3e62282513e03d78fc6c5bd555503d0c697bf394d55cd672cc96e6b0a2c")
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_length() {
+        let cell = CellOutputWithData {
+            data: b"hello world".to_vec().into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            query_object(cell, "dataLength").await,
+            serde_json::json!({ "dataLength": "0xb" })
+        );
+    }
+
+    #[test]
+    fn test_capacity_margin() {
+        let mut output = CellOutput {
+            capacity: Capacity::new(71 * BYTE_SHANNONS),
+            lock:     Script {
+                args: vec![0u8; 20].into(),
+                ..Script::random()
+            },
+            type_:    None,
+        };
+
+        assert_eq!(output.capacity_margin(0), 10 * BYTE_SHANNONS as i128);
+        assert_eq!(output.capacity_margin(10), 0);
+        assert_eq!(output.capacity_margin(11), -(BYTE_SHANNONS as i128));
+
+        output.capacity = Capacity::new(u64::MAX);
+        assert_eq!(
+            output.capacity_margin(usize::MAX),
+            u64::MAX as i128 - (61 + usize::MAX as i128) * BYTE_SHANNONS as i128
+        );
+    }
+
+    #[test]
+    fn test_is_capacity_sufficient() {
+        let mut output = CellOutput {
+            capacity: Capacity::new(71 * BYTE_SHANNONS),
+            lock:     Script {
+                args: vec![0u8; 20].into(),
+                ..Script::random()
+            },
+            type_:    None,
+        };
+
+        assert_eq!(
+            output.occupied_capacity(10).unwrap(),
+            Capacity::new(71 * BYTE_SHANNONS)
+        );
+        assert!(output.is_capacity_sufficient(10));
+        assert!(output.is_capacity_sufficient(9));
+        assert!(!output.is_capacity_sufficient(11));
+
+        output.capacity = Capacity::new(u64::MAX);
+        assert!(matches!(
+            output.occupied_capacity(usize::MAX),
+            Err(Error::CapacityOverflow)
+        ));
+        assert!(!output.is_capacity_sufficient(usize::MAX));
+    }
+
+    #[test]
+    fn test_constructors() {
+        let args = GraphqlBytes::from(vec![0u8; 20]);
+        let lock = Script::new(
+            SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            ScriptHashType::Type,
+            args.clone(),
+        );
+        assert_eq!(lock.code_hash, SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH);
+        assert_eq!(lock.hash_type, ScriptHashType::Type);
+        assert_eq!(lock.args, args);
+
+        let output = CellOutput::new(Capacity::new(61 * BYTE_SHANNONS), lock.clone(), None);
+        assert_eq!(output.capacity, min_capacity_for_lock(&lock));
+        assert_eq!(output.lock, lock);
+        assert_eq!(output.type_, None);
+    }
+
+    #[test]
+    fn test_type_script_size() {
+        let mut output = CellOutput::random();
+
+        output.type_ = None;
+        assert_eq!(output.type_script_size(), 0);
+
+        output.type_ = Some(Script {
+            args: vec![0u8; 20].into(),
+            ..Script::random()
+        });
+        assert_eq!(output.type_script_size(), 53);
+    }
+
+    #[test]
+    fn test_min_capacity_for_lock() {
+        let lock = Script {
+            args: H160::random().0.to_vec().into(),
+            ..Script::random()
+        };
+
+        assert_eq!(
+            min_capacity_for_lock(&lock),
+            Capacity::new(61 * BYTE_SHANNONS)
+        );
+    }
+
+    #[test]
+    fn test_as_ckb_checked() {
+        let capacity = Capacity::new(61 * BYTE_SHANNONS + 1);
+        assert_eq!(capacity_as_ckb_checked(&capacity).unwrap(), 61.00000001);
+
+        let capacity = Capacity::new((1 << 53) + 1);
+        assert!(matches!(
+            capacity_as_ckb_checked(&capacity),
+            Err(Error::PrecisionLoss(_))
+        ));
+        assert_eq!(capacity_as_ckb(&capacity), (1u64 << 53) as f64 / 1e8);
+    }
+
+    #[test]
+    fn test_is_system_dep() {
+        let dep = crate::network::MAINNET_SECP256K1_BLAKE160_DEP;
+
+        assert!(dep.is_system_dep(Network::Mainnet));
+        assert!(!dep.is_system_dep(Network::Testnet));
+        assert!(!CellDep::random().is_system_dep(Network::Mainnet));
+    }
+
+    #[test]
+    fn test_acp() {
+        let network = Network::Testnet;
+        let acp = |args: Vec<u8>| Script {
+            code_hash: network.acp_code_hash(),
+            hash_type: ScriptHashType::Type,
+            args:      args.into(),
+        };
+
+        let bare = acp(vec![0u8; 20]);
+        assert!(bare.is_acp(network));
+        assert!(!bare.is_acp(Network::Mainnet));
+        assert_eq!(bare.acp_minimums(network), Some((None, None)));
+
+        let mut args = vec![0u8; 20];
+        args.extend([9, 2]);
+        let with_minimums = acp(args);
+        assert!(with_minimums.is_acp(network));
+        assert_eq!(with_minimums.acp_minimums(network), Some((Some(9), Some(2))));
+
+        assert!(!acp(vec![0u8; 23]).is_acp(network));
+        assert_eq!(with_minimums.acp_minimums(Network::Mainnet), None);
+
+        let sighash = Script {
+            code_hash: SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            hash_type: ScriptHashType::Type,
+            args:      vec![0u8; 21].into(),
+        };
+        assert_eq!(sighash.acp_minimums(network), None);
+    }
+
+    #[test]
+    fn test_script_hash_type_from_str() {
+        for hash_type in [
+            ScriptHashType::Data,
+            ScriptHashType::Type,
+            ScriptHashType::Data1,
+        ] {
+            assert_eq!(
+                hash_type.to_string().parse::<ScriptHashType>().unwrap(),
+                hash_type
+            );
+        }
+        assert!("data2x".parse::<ScriptHashType>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_script_hash_type_input() {
+        use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+        struct Query;
+
+        #[Object]
+        impl Query {
+            async fn hash_type(&self, hash_type: ScriptHashTypeString) -> ScriptHashType {
+                hash_type.into()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        let res = schema.execute(r#"{ hashType(hashType: "data1") }"#).await;
+        assert!(res.errors.is_empty(), "{:?}", res.errors);
+        assert_eq!(
+            res.data.into_json().unwrap(),
+            serde_json::json!({ "hashType": "DATA_1" })
+        );
+
+        let res = schema.execute(r#"{ hashType(hashType: "data2x") }"#).await;
+        assert_eq!(res.errors.len(), 1);
+        assert!(res.errors[0]
+            .message
+            .contains("Invalid script hash type data2x"));
+    }
+
+    #[test]
+    fn test_well_known_name() {
+        let mut script = Script {
+            code_hash: SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            hash_type: ScriptHashType::Type,
+            args:      GraphqlBytes::random(),
+        };
+        assert_eq!(
+            script.well_known_name(),
+            Some("secp256k1_blake160_sighash_all")
+        );
+        assert!(script.is_default_lock());
+
+        script.code_hash = SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH;
+        assert_eq!(
+            script.well_known_name(),
+            Some("secp256k1_blake160_multisig_all")
+        );
+        assert!(!script.is_default_lock());
+
+        script.hash_type = ScriptHashType::Data;
+        assert_eq!(script.well_known_name(), None);
+        assert_eq!(Script::random().well_known_name(), None);
+    }
+
+    #[tokio::test]
+    async fn test_script_id() {
+        let script = Script::new(
+            SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            ScriptHashType::Type,
+            GraphqlBytes::random(),
+        );
+        let expected = "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8-type";
+
+        assert_eq!(script.script_id(), expected);
+        assert_eq!(
+            query_object(script.clone(), "scriptId").await,
+            serde_json::json!({ "scriptId": expected })
+        );
+        assert_eq!(
+            Script {
+                args: GraphqlBytes::random(),
+                ..script.clone()
+            }
+            .script_id(),
+            expected
+        );
+        assert_ne!(
+            Script {
+                hash_type: ScriptHashType::Data1,
+                ..script
+            }
+            .script_id(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_network_hint() {
+        for (code_hash, name, network) in [
+            (
+                SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+                "secp256k1_blake160_sighash_all",
+                None,
+            ),
+            (
+                SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH,
+                "secp256k1_blake160_multisig_all",
+                None,
+            ),
+            (MAINNET_SUDT_TYPE_HASH, "sudt", Some(Network::Mainnet)),
+            (TESTNET_SUDT_TYPE_HASH, "sudt", Some(Network::Testnet)),
+        ] {
+            let script = Script::new(code_hash, ScriptHashType::Type, GraphqlBytes::random());
+            assert_eq!(script.well_known_name(), Some(name));
+            assert_eq!(script.network_hint(), network);
+        }
+        assert_eq!(Script::random().network_hint(), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut script = Script {
+            code_hash: SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            hash_type: ScriptHashType::Type,
+            args:      vec![0u8; 20].into(),
+        };
+        assert!(script.validate().is_ok());
+
+        script.args = vec![0u8; 21].into();
+        assert!(matches!(
+            script.validate(),
+            Err(Error::InvalidScriptArgs {
+                expected: 20,
+                actual: 21,
+                ..
+            })
+        ));
+
+        assert!(Script::random().validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_dep_group() {
+        let members = vec![OutPoint::random(), OutPoint::random()];
+        let data = members
+            .iter()
+            .cloned()
+            .map(packed::OutPoint::from)
+            .collect::<Vec<_>>()
+            .pack();
+        let data = GraphqlBytes::from(data.as_slice().to_vec());
+
+        assert_eq!(parse_dep_group(&data).unwrap(), members);
+        assert!(parse_dep_group(&GraphqlBytes::default()).is_err());
+        assert!(matches!(
+            parse_dep_group(&data.slice(..data.len() - 1)),
+            Err(Error::Molecule(_))
+        ));
+    }
+
+    #[test]
+    fn test_dedup_out_points() {
+        let (a, b, c) = (OutPoint::random(), OutPoint::random(), OutPoint::random());
+
+        let mut out_points = vec![a.clone(), b.clone(), a.clone(), c.clone(), b.clone()];
+        dedup_out_points(&mut out_points);
+        assert_eq!(out_points, vec![a.clone(), b.clone(), c.clone()]);
+
+        let mut out_points = vec![c.clone(), b.clone(), a.clone()];
+        dedup_out_points(&mut out_points);
+        assert_eq!(out_points, vec![c, b, a]);
+    }
+
+    #[test]
+    fn test_out_point_display() {
+        let null = OutPoint {
+            tx_hash: H256::default(),
+            index:   Uint32::new(u32::MAX),
+        };
+        assert!(null.is_cellbase_index());
+        assert_eq!(null.to_string(), "null");
+
+        let tx_hash = H256::new([1u8; 32]);
+        let out_point = OutPoint {
+            tx_hash: tx_hash.clone(),
+            index:   Uint32::new(2),
+        };
+        assert!(!out_point.is_cellbase_index());
+        assert_eq!(
+            out_point.to_string(),
+            format!("{}:2", crate::hex::hex_encode(&tx_hash))
+        );
+
+        let out_point = OutPoint {
+            tx_hash: tx_hash.clone(),
+            index:   Uint32::new(u32::MAX),
+        };
+        assert_eq!(
+            out_point.to_string(),
+            format!("{}:0xffffffff", crate::hex::hex_encode(&tx_hash))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cell_input_since_value() {
+        let input =
+            CellInput::random().with_since_value(SinceValue::RelativeEpoch(Uint64::new(42)));
+        assert_eq!(input.since, Uint64::new(0xa000_0000_0000_002a));
+        assert_eq!(
+            input.since_value(),
+            SinceValue::RelativeEpoch(Uint64::new(42))
+        );
+
+        assert_eq!(
+            query_object(input, "sinceValue { relative metric value }").await,
+            serde_json::json!({
+                "sinceValue": {
+                    "relative": true,
+                    "metric": "EPOCH_NUMBER_WITH_FRACTION",
+                    "value": "0x2a",
+                },
+            })
+        );
+
+        let input = CellInput {
+            since: Uint64::new(u64::MAX),
+            ..Default::default()
+        };
+        assert_eq!(
+            query_object(input, "sinceValue { relative }").await,
+            serde_json::json!({ "sinceValue": null })
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_blake160_dep() {
+        for (network, tx_hash) in [
+            (
+                Network::Mainnet,
+                "0x71a7ba8fc96349fea0ed3a5c47992e3b4084b031a42264a018e0072e8172e46c",
+            ),
+            (
+                Network::Testnet,
+                "0xf8de3bb47d055cdf460d93a2a6e1b05f7432f9777c8c474abf4eec1d4aee5d37",
+            ),
+        ] {
+            let dep = CellDep::secp256k1_blake160(network);
+            assert_eq!(dep.out_point.tx_hash, tx_hash.parse::<H256>().unwrap());
+            assert_eq!(dep.out_point.index, Uint32::new(0));
+            assert_eq!(dep.dep_type, DepType::DepGroup);
+            assert!(dep.is_system_dep(network));
+        }
+    }
+}
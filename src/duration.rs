@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::{error::Error, Uint64};
+
+/// The unit a [`Duration`] is measured in.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DurationUnit {
+    /// A number of blocks.
+    Blocks,
+    /// A number of epochs.
+    Epochs,
+}
+
+impl DurationUnit {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Epochs => "epochs",
+        }
+    }
+}
+
+impl Display for DurationUnit {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.suffix())
+    }
+}
+
+/// A lock-up duration expressed in blocks or epochs.
+///
+/// This is encoded as a decimal number followed by the unit, such as
+/// `"100blocks"` or `"180epochs"`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Duration {
+    /// The unit of the duration.
+    pub unit:  DurationUnit,
+    /// The number of units.
+    pub value: Uint64,
+}
+
+impl Duration {
+    pub fn new(unit: DurationUnit, value: u64) -> Self {
+        Self {
+            unit,
+            value: Uint64::new(value),
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.value.0, self.unit)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for unit in [DurationUnit::Blocks, DurationUnit::Epochs] {
+            if let Some(value) = s.strip_suffix(unit.suffix()) {
+                return Ok(Self::new(unit, value.parse()?));
+            }
+        }
+
+        Err(Error::InvalidDuration(s.to_owned()))
+    }
+}
+
+#[Scalar]
+impl ScalarType for Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        if let Value::String(value) = &value {
+            return Self::from_str(value).map_err(InputValueError::custom);
+        }
+        Err(InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration() {
+        for (s, expected) in [
+            ("100blocks", Duration::new(DurationUnit::Blocks, 100)),
+            ("180epochs", Duration::new(DurationUnit::Epochs, 180)),
+        ] {
+            let value = Value::String(s.to_owned());
+            let duration = <Duration as ScalarType>::parse(value.clone()).unwrap();
+
+            assert_eq!(duration, expected);
+            assert_eq!(duration.to_value(), value);
+        }
+    }
+
+    #[test]
+    fn test_malformed_duration() {
+        assert!(matches!(
+            Duration::from_str("100days"),
+            Err(Error::InvalidDuration(_))
+        ));
+        assert!(matches!(
+            Duration::from_str("blocks"),
+            Err(Error::ParseUint(_))
+        ));
+        assert!(<Duration as ScalarType>::parse(Value::String("0x64".to_owned())).is_err());
+    }
+}
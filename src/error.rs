@@ -2,6 +2,8 @@ use std::num::ParseIntError;
 
 use thiserror::Error;
 
+use crate::H256;
+
 #[derive(Error, Clone, Debug)]
 pub enum Error {
     #[error("Parse Bytes error")]
@@ -15,6 +17,80 @@ pub enum Error {
 
     #[error("Invalid hex prefix")]
     HexPrefix,
+
+    #[error("Non-canonical uint hex {0}, leading zeros are not allowed")]
+    NonCanonicalHex(String),
+
+    #[error("Unsupported transaction version {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("Duplicate header dep {}", crate::hex::hex_encode(.0))]
+    DuplicateHeaderDep(H256),
+
+    #[error("Invalid duration {0}")]
+    InvalidDuration(String),
+
+    #[error("Invalid epoch {number} {index}/{length}, out of range")]
+    InvalidEpoch {
+        number: u64,
+        index:  u64,
+        length: u64,
+    },
+
+    #[error("Invalid length, expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("Invalid script hash type {0}")]
+    InvalidScriptHashType(String),
+
+    #[error("Invalid {name} script args, expected {expected} bytes, got {actual}")]
+    InvalidScriptArgs {
+        name:     &'static str,
+        expected: usize,
+        actual:   usize,
+    },
+
+    #[error("Wrong witness lock length, expected {expected} bytes, got {actual}")]
+    WrongLockLength { expected: usize, actual: usize },
+
+    #[error("Invalid item at index {index}: {source}")]
+    InvalidListItem { index: usize, source: Box<Error> },
+
+    #[error("{0} shannons can not be represented exactly in CKB as f64")]
+    PrecisionLoss(u64),
+
+    #[error("Expected {expected} input capacities, got {actual}")]
+    InputCapacitiesMismatch { expected: usize, actual: usize },
+
+    #[error("Capacity overflow")]
+    CapacityOverflow,
+
+    #[error("Insufficient input capacity {inputs}, outputs require {outputs}")]
+    InsufficientInputCapacity { inputs: u64, outputs: u64 },
+
+    #[error("Index {index} out of range for length {len}")]
+    OutOfRange { index: usize, len: usize },
+
+    #[error("{outputs} outputs but {outputs_data} outputs data")]
+    OutputsDataMismatch {
+        outputs:      usize,
+        outputs_data: usize,
+    },
+
+    #[error("Invalid transaction structure: {0}")]
+    StructureMismatch(&'static str),
+
+    #[error("JSON error {0}")]
+    Json(String),
+
+    #[error("Too long, at most {max} bytes, got {actual}")]
+    TooLong { max: usize, actual: usize },
+
+    #[error("Invalid address {0}")]
+    Address(String),
+
+    #[error("Molecule verification error {0}")]
+    Molecule(String),
 }
 
 impl From<faster_hex::Error> for Error {
@@ -23,6 +99,19 @@ impl From<faster_hex::Error> for Error {
     }
 }
 
+impl From<molecule::error::VerificationError> for Error {
+    fn from(err: molecule::error::VerificationError) -> Self {
+        Error::Molecule(err.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err.to_string())
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(err: ParseIntError) -> Self {
         Error::ParseUint(err)
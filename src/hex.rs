@@ -4,8 +4,15 @@ use crate::error::Error;
 
 const HEX_PREFIX: &str = "0x";
 
+/// Encode bytes as a 0x-prefixed hex string, with a single allocation.
 pub fn hex_encode<T: AsRef<[u8]>>(src: T) -> String {
-    HEX_PREFIX.to_string() + &faster_hex::hex_string(src.as_ref())
+    let src = src.as_ref();
+    let mut buf = vec![0u8; HEX_PREFIX.len() + src.len() * 2];
+    buf[..HEX_PREFIX.len()].copy_from_slice(HEX_PREFIX.as_bytes());
+    faster_hex::hex_encode(src, &mut buf[HEX_PREFIX.len()..])
+        .expect("the buffer fits twice the source length");
+
+    String::from_utf8(buf).expect("hex digits are ASCII")
 }
 
 pub fn hex_decode(src: &str) -> Result<Vec<u8>, Error> {
@@ -14,6 +21,10 @@ pub fn hex_decode(src: &str) -> Result<Vec<u8>, Error> {
     }
 
     let src = clean_0x(src)?;
+    if src.is_empty() {
+        return Ok(Vec::new());
+    }
+
     let src = src.as_bytes();
     let mut ret = vec![0u8; src.len() / 2];
     faster_hex::hex_decode(src, &mut ret)?;
@@ -21,14 +32,82 @@ pub fn hex_decode(src: &str) -> Result<Vec<u8>, Error> {
     Ok(ret)
 }
 
+/// Encode big-endian bytes as an uint hex string without leading zeros.
+pub fn hex_be_uint<T: AsRef<[u8]>>(src: T) -> String {
+    let hex = faster_hex::hex_string(src.as_ref());
+    let trimmed = hex.trim_start_matches('0');
+
+    if trimmed.is_empty() {
+        HEX_PREFIX.to_string() + "0"
+    } else {
+        HEX_PREFIX.to_string() + trimmed
+    }
+}
+
 pub fn hex_uint<T: LowerHex>(src: T) -> String {
     HEX_PREFIX.to_string() + &format!("{:x}", src)
 }
 
-pub fn clean_0x(s: &str) -> Result<String, Error> {
-    if s.starts_with("0x") || s.starts_with("0X") {
-        Ok(s[2..].to_owned())
-    } else {
-        Err(Error::HexPrefix)
+/// Strip the `0x` prefix, borrowing the rest of `s` without allocating.
+pub fn clean_0x(s: &str) -> Result<&str, Error> {
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .ok_or(Error::HexPrefix)
+}
+
+/// Check that an uint hex string has digits and no leading zeros, except for
+/// `"0x0"`.
+pub fn check_canonical_uint(s: &str) -> Result<(), Error> {
+    let digits = clean_0x(s)?;
+
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return Err(Error::NonCanonicalHex(s.to_owned()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uint64;
+
+    #[test]
+    fn test_hex_encode() {
+        for len in [0, 1, 20, 32, 33, 1000] {
+            let bytes = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            let encoded = hex_encode(&bytes);
+
+            assert_eq!(
+                encoded,
+                HEX_PREFIX.to_string() + &faster_hex::hex_string(&bytes)
+            );
+            assert_eq!(encoded.len(), 2 + 2 * len);
+            assert_eq!(hex_decode(&encoded).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_clean_0x_borrows() {
+        let s = "0xdeadbeef";
+        let digits = clean_0x(s).unwrap();
+        assert_eq!(digits, "deadbeef");
+        assert_eq!(digits.as_ptr(), s[2..].as_ptr());
+
+        assert_eq!(clean_0x("0X").unwrap(), "");
+        assert!(matches!(clean_0x("deadbeef"), Err(Error::HexPrefix)));
+    }
+
+    #[test]
+    fn test_parse_large_uint_list() {
+        let list = (0..10_000u64).map(hex_uint).collect::<Vec<_>>();
+        let parsed = list
+            .iter()
+            .map(|s| s.parse::<Uint64>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(parsed.len(), list.len());
+        assert!(parsed.iter().enumerate().all(|(i, v)| v.0 == i as u64));
     }
 }
@@ -0,0 +1,263 @@
+use async_graphql::InputObject;
+#[cfg(feature = "address")]
+use async_graphql::OneofObject;
+
+use crate::{
+    cell::DepType, Capacity, CellDep, CellInput, CellOutput, GraphqlBytes, OutPoint, Script,
+    ScriptHashType, TransactionView, Uint32, Uint64, Version, H256,
+};
+
+/// The input version of [Script](struct.Script.html).
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ScriptInput {
+    /// The hash used to match the script code.
+    pub code_hash: H256,
+    /// Specifies how to use the `code_hash` to match the script code.
+    pub hash_type: ScriptHashType,
+    /// Arguments for script.
+    pub args:      GraphqlBytes,
+}
+
+impl From<ScriptInput> for Script {
+    fn from(value: ScriptInput) -> Self {
+        Self {
+            code_hash: value.code_hash,
+            hash_type: value.hash_type,
+            args:      value.args,
+        }
+    }
+}
+
+/// A lock script given either as a full script or as an address.
+///
+/// Exactly one of the fields must be set.
+#[cfg(feature = "address")]
+#[derive(OneofObject, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LockInput {
+    /// The lock script.
+    Script(ScriptInput),
+    /// The address encoding the lock script, on either network.
+    Address(String),
+}
+
+/// Decodes the address with [Script::from_address](struct.Script.html#method.
+/// from_address).
+#[cfg(feature = "address")]
+impl TryFrom<LockInput> for Script {
+    type Error = crate::error::Error;
+
+    fn try_from(value: LockInput) -> Result<Self, Self::Error> {
+        match value {
+            LockInput::Script(script) => Ok(script.into()),
+            LockInput::Address(address) => Script::from_address(&address),
+        }
+    }
+}
+
+/// The input version of [CellOutput](struct.CellOutput.html).
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CellOutputInput {
+    /// The cell capacity.
+    pub capacity: Capacity,
+    /// The lock script.
+    pub lock:     ScriptInput,
+    /// The optional type script.
+    pub type_:    Option<ScriptInput>,
+}
+
+impl From<CellOutputInput> for CellOutput {
+    fn from(value: CellOutputInput) -> Self {
+        Self {
+            capacity: value.capacity,
+            lock:     value.lock.into(),
+            type_:    value.type_.map(Into::into),
+        }
+    }
+}
+
+/// The input version of [OutPoint](struct.OutPoint.html).
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OutPointInput {
+    /// Transaction hash in which the cell is an output.
+    pub tx_hash: H256,
+    /// The output index of the cell in the transaction specified by `tx_hash`.
+    pub index:   Uint32,
+}
+
+impl From<OutPointInput> for OutPoint {
+    fn from(value: OutPointInput) -> Self {
+        Self {
+            tx_hash: value.tx_hash,
+            index:   value.index,
+        }
+    }
+}
+
+/// The input version of [CellInput](struct.CellInput.html).
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CellInputInput {
+    /// Restrict when the transaction can be committed into the chain.
+    pub since:           Uint64,
+    /// Reference to the input cell.
+    pub previous_output: OutPointInput,
+}
+
+impl From<CellInputInput> for CellInput {
+    fn from(value: CellInputInput) -> Self {
+        Self {
+            since:           value.since,
+            previous_output: value.previous_output.into(),
+        }
+    }
+}
+
+/// The input version of [CellDep](struct.CellDep.html).
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CellDepInput {
+    /// Reference to the cell.
+    pub out_point: OutPointInput,
+    /// Dependency type.
+    pub dep_type:  DepType,
+}
+
+impl From<CellDepInput> for CellDep {
+    fn from(value: CellDepInput) -> Self {
+        Self {
+            out_point: value.out_point.into(),
+            dep_type:  value.dep_type,
+        }
+    }
+}
+
+/// The input version of [TransactionView](struct.TransactionView.html).
+///
+/// There is no `hash` field, the hash is computed when converting to
+/// `TransactionView`.
+#[derive(InputObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TransactionInput {
+    /// Reserved for future usage. It must equal 0 in current version.
+    pub version:      Version,
+    /// An array of cell deps.
+    pub cell_deps:    Vec<CellDepInput>,
+    /// An array of header deps.
+    pub header_deps:  Vec<H256>,
+    /// An array of input cells.
+    pub inputs:       Vec<CellInputInput>,
+    /// An array of output cells.
+    pub outputs:      Vec<CellOutputInput>,
+    /// Output cells data.
+    pub outputs_data: Vec<GraphqlBytes>,
+    /// An array of variable-length binaries.
+    pub witnesses:    Vec<GraphqlBytes>,
+}
+
+impl From<TransactionInput> for TransactionView {
+    fn from(value: TransactionInput) -> Self {
+        Self {
+            version:      value.version,
+            cell_deps:    value.cell_deps.into_iter().map(Into::into).collect(),
+            header_deps:  value.header_deps,
+            inputs:       value.inputs.into_iter().map(Into::into).collect(),
+            outputs:      value.outputs.into_iter().map(Into::into).collect(),
+            outputs_data: value.outputs_data,
+            witnesses:    value.witnesses,
+            hash:         H256::default(),
+        }
+        .with_recomputed_hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ckb_types::packed;
+
+    use super::*;
+
+    #[test]
+    fn test_transaction_input() {
+        let lock = ScriptInput {
+            code_hash: H256::random(),
+            hash_type: ScriptHashType::Type,
+            args:      GraphqlBytes::random(),
+        };
+        let out_point = OutPointInput {
+            tx_hash: H256::random(),
+            index:   Uint32::new(1),
+        };
+        let input = TransactionInput {
+            version:      Version::new(0),
+            cell_deps:    vec![CellDepInput {
+                out_point: out_point.clone(),
+                dep_type:  DepType::DepGroup,
+            }],
+            header_deps:  vec![H256::random()],
+            inputs:       vec![CellInputInput {
+                since:           Uint64::new(0),
+                previous_output: out_point,
+            }],
+            outputs:      vec![CellOutputInput {
+                capacity: Capacity::new(61_0000_0000),
+                lock:     lock.clone(),
+                type_:    Some(lock),
+            }],
+            outputs_data: vec![GraphqlBytes::random()],
+            witnesses:    vec![GraphqlBytes::random()],
+        };
+
+        let view = TransactionView::from(input.clone());
+        assert_eq!(
+            view.outputs[0].lock,
+            Script::from(input.outputs[0].lock.clone())
+        );
+        assert_eq!(view.cell_deps[0].dep_type, DepType::DepGroup);
+
+        let tx = packed::Transaction::from(view.clone());
+        assert_eq!(H256::from(tx.calc_tx_hash()), view.hash);
+        assert_eq!(tx.raw().outputs().len(), 1);
+        assert_eq!(tx.witnesses().len(), 1);
+    }
+
+    #[cfg(feature = "address")]
+    #[tokio::test]
+    async fn test_lock_input() {
+        use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+        struct Query;
+
+        #[Object]
+        impl Query {
+            async fn lock(&self, lock: LockInput) -> async_graphql::Result<Script> {
+                Ok(lock.try_into()?)
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let expected = serde_json::json!({
+            "lock": {
+                "codeHash": "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8",
+                "hashType": "TYPE",
+                "args": "0xb39bbc0b3673c7d36450bc14cfcdad2d559c6c64",
+            },
+        });
+
+        for lock in [
+            r#"{ script: {
+                codeHash: "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8",
+                hashType: TYPE,
+                args: "0xb39bbc0b3673c7d36450bc14cfcdad2d559c6c64"
+            } }"#,
+            r#"{ address: "ckb1qzda0cr08m85hc8jlnfp3zer7xulejywt49kt2rr0vthywaa50xwsqdnnw7qkdnnclfkg59uzn8umtfd2kwxceqxwquc4" }"#,
+        ] {
+            let res = schema
+                .execute(format!("{{ lock(lock: {lock}) {{ codeHash hashType args }} }}"))
+                .await;
+            assert!(res.errors.is_empty(), "{:?}", res.errors);
+            assert_eq!(res.data.into_json().unwrap(), expected);
+        }
+
+        let res = schema
+            .execute(r#"{ lock(lock: { address: "ckb1invalid" }) { args } }"#)
+            .await;
+        assert_eq!(res.errors.len(), 1);
+    }
+}
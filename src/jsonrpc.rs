@@ -0,0 +1,200 @@
+use ckb_jsonrpc_types as rpc;
+use ckb_types::packed;
+
+use crate::{CellInfo, CellStatus, CellWithStatus, GraphqlBytes, Script, TransactionView};
+
+impl From<rpc::JsonBytes> for GraphqlBytes {
+    fn from(value: rpc::JsonBytes) -> Self {
+        Self(value.into_bytes())
+    }
+}
+
+impl From<GraphqlBytes> for rpc::JsonBytes {
+    fn from(value: GraphqlBytes) -> Self {
+        Self::from_bytes(value.0)
+    }
+}
+
+impl From<rpc::Script> for Script {
+    fn from(value: rpc::Script) -> Self {
+        packed::Script::from(value).into()
+    }
+}
+
+impl From<Script> for rpc::Script {
+    fn from(value: Script) -> Self {
+        packed::Script::from(value).into()
+    }
+}
+
+impl From<rpc::TransactionView> for TransactionView {
+    fn from(value: rpc::TransactionView) -> Self {
+        packed::Transaction::from(value.inner).into()
+    }
+}
+
+impl From<TransactionView> for rpc::TransactionView {
+    fn from(value: TransactionView) -> Self {
+        packed::Transaction::from(value).into_view().into()
+    }
+}
+
+impl TransactionView {
+    /// The JSON of the transaction in the same shape as the CKB JSON-RPC, with
+    /// snake_case keys and 0x-prefixed hex values.
+    #[cfg(feature = "serde")]
+    pub fn to_rpc_json(&self) -> serde_json::Value {
+        serde_json::to_value(rpc::TransactionView::from(self.clone()))
+            .expect("rpc transaction view is always serializable")
+    }
+}
+
+impl From<rpc::CellInfo> for CellInfo {
+    fn from(value: rpc::CellInfo) -> Self {
+        Self {
+            output: packed::CellOutput::from(value.output).into(),
+            data:   value
+                .data
+                .map(|data| data.content.into())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The RPC status is a string, an unrecognized status maps to
+/// [CellStatus::Unknown].
+impl From<rpc::CellWithStatus> for CellWithStatus {
+    fn from(value: rpc::CellWithStatus) -> Self {
+        let status = match value.status.as_str() {
+            "live" => CellStatus::Live,
+            "dead" => CellStatus::Dead,
+            _ => CellStatus::Unknown,
+        };
+
+        Self {
+            cell: value.cell.map(Into::into),
+            status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::H256;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        use async_graphql::ScalarType;
+
+        for bytes in [GraphqlBytes::default(), GraphqlBytes::random()] {
+            let json_bytes = rpc::JsonBytes::from(bytes.clone());
+
+            assert_eq!(json_bytes.as_bytes(), &bytes.0[..]);
+            assert_eq!(
+                bytes.to_value(),
+                async_graphql::Value::from_json(serde_json::to_value(&json_bytes).unwrap())
+                    .unwrap()
+            );
+            assert_eq!(GraphqlBytes::from(json_bytes), bytes);
+        }
+    }
+
+    #[test]
+    fn test_script_round_trip() {
+        let script = Script::random();
+        let rpc_script = rpc::Script::from(script.clone());
+
+        assert_eq!(H256::from(rpc_script.code_hash.clone()), script.code_hash);
+        assert_eq!(rpc_script.args.as_bytes(), &script.args.0[..]);
+        assert_eq!(Script::from(rpc_script), script);
+    }
+
+    #[test]
+    fn test_transaction_round_trip() {
+        let tx = TransactionView::random();
+        let rpc_tx = rpc::TransactionView::from(tx.clone());
+
+        assert_eq!(H256::from(rpc_tx.hash.clone()), tx.hash);
+        assert_eq!(rpc_tx.inner.version.value(), tx.version.0);
+        assert_eq!(TransactionView::from(rpc_tx), tx);
+    }
+
+    #[test]
+    fn test_cell_with_status() {
+        for (status, expected) in [
+            ("live", CellStatus::Live),
+            ("dead", CellStatus::Dead),
+            ("unknown", CellStatus::Unknown),
+        ] {
+            let value = rpc::CellWithStatus {
+                cell:   None,
+                status: status.to_owned(),
+            };
+            assert_eq!(CellWithStatus::from(value).status, expected);
+        }
+
+        let output = crate::CellOutput::random();
+        let data = GraphqlBytes::random();
+        let value = rpc::CellWithStatus {
+            cell:   Some(rpc::CellInfo {
+                output: packed::CellOutput::from(output.clone()).into(),
+                data:   Some(rpc::CellData {
+                    content: data.clone().into(),
+                    hash:    ckb_types::H256::default(),
+                }),
+            }),
+            status: "live".to_owned(),
+        };
+
+        assert_eq!(CellWithStatus::from(value), CellWithStatus {
+            cell:   Some(CellInfo { output, data }),
+            status: CellStatus::Live,
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_rpc_json() {
+        // The `transaction` of the `get_transaction` response documented in
+        // the CKB RPC README.
+        let json = serde_json::json!({
+            "cell_deps": [{
+                "dep_type": "code",
+                "out_point": {
+                    "index": "0x0",
+                    "tx_hash": "0xa4037a893eb48e18ed4ef61034ce26eba9c585f15c9cee102ae58505565eccc3"
+                }
+            }],
+            "hash": "0xa0ef4eb5f4ceeb08a4c8524d84c5da95dce2f608e0ca2ec8091191b0f330c6e3",
+            "header_deps": [
+                "0x7978ec7ce5b507cfb52e149e36b1a23f6062ed150503c85bbf825da3599095ed"
+            ],
+            "inputs": [{
+                "previous_output": {
+                    "index": "0x0",
+                    "tx_hash": "0x365698b50ca0da75dca2c87f9e7b563811d3b5813736b8cc62cc3b106faceb17"
+                },
+                "since": "0x0"
+            }],
+            "outputs": [{
+                "capacity": "0x2540be400",
+                "lock": {
+                    "code_hash": "0x28e83a1277d48add8e72fadaa9248559e1b632bab2bd60b27955ebc4c03800a5",
+                    "hash_type": "data",
+                    "args": "0x"
+                },
+                "type": null
+            }],
+            "outputs_data": ["0x"],
+            "version": "0x0",
+            "witnesses": []
+        });
+        let tx = serde_json::from_value::<rpc::TransactionView>(json.clone()).unwrap();
+
+        let view = TransactionView::from(tx);
+
+        assert_eq!(view.calc_tx_hash(), view.hash);
+        assert_eq!(view.to_rpc_json(), json);
+    }
+}
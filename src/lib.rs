@@ -1,19 +1,119 @@
+#[cfg(feature = "address")]
+mod address;
+#[cfg(feature = "ckb")]
 mod blockchain;
+#[cfg(feature = "ckb")]
 mod cell;
+mod duration;
 pub mod error;
 mod hex;
+#[cfg(feature = "ckb")]
+mod input;
+#[cfg(feature = "jsonrpc")]
+mod jsonrpc;
+#[cfg(feature = "ckb")]
+mod network;
+mod pow;
+#[cfg(feature = "ckb")]
+mod schema;
+mod since;
+#[cfg(feature = "ckb")]
 mod transaction;
+#[cfg(feature = "ckb")]
+mod witness;
 
-pub use cell::{CellDep, CellInput, CellOutput, OutPoint, Script, ScriptHashType};
-pub use transaction::TransactionView;
+#[cfg(feature = "ckb")]
+pub use blockchain::{
+    decode_dao, transaction_views, Block, BlockEconomicState, BlockIssuance, DaoFields, Epoch,
+    Header, MinerReward, UncleBlock,
+};
+#[cfg(feature = "ckb")]
+pub use cell::{
+    capacity_as_ckb, capacity_as_ckb_checked, dedup_out_points, min_capacity_for_lock,
+    parse_dep_group, CellDep, CellInfo, CellInput, CellOutput, CellOutputWithData, CellStatus,
+    CellWithStatus, DepType, OutPoint, Script, ScriptHashType, ScriptHashTypeString,
+};
+pub use duration::{Duration, DurationUnit};
+#[cfg(feature = "ckb")]
+pub use input::{
+    CellDepInput, CellInputInput, CellOutputInput, OutPointInput, ScriptInput, TransactionInput,
+};
+#[cfg(feature = "address")]
+pub use input::LockInput;
+#[cfg(feature = "ckb")]
+pub use network::{
+    Network, MAINNET_ACP_TYPE_HASH, MAINNET_DAO_DEP, MAINNET_SECP256K1_BLAKE160_DEP,
+    MAINNET_SECP256K1_MULTISIG_DEP, MAINNET_SUDT_TYPE_HASH,
+    SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH, SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+    TESTNET_ACP_TYPE_HASH, TESTNET_DAO_DEP, TESTNET_SECP256K1_BLAKE160_DEP,
+    TESTNET_SECP256K1_MULTISIG_DEP, TESTNET_SUDT_TYPE_HASH,
+};
+pub use pow::{
+    compact_to_target, compact_to_target_saturating, target_to_compact, MAX_TARGET,
+};
+#[cfg(feature = "ckb")]
+pub use schema::{register_types, schema_fingerprint};
+pub use since::{SinceFields, SinceMetric, SinceValue};
+#[cfg(feature = "ckb")]
+pub use transaction::{
+    dedup_transactions, RawTransactionView, TransactionView, TransactionViewBuilder,
+};
+#[cfg(feature = "ckb")]
+pub use witness::WitnessArgs;
 
-use ckb_types::{packed, prelude::Unpack};
+#[cfg(feature = "ckb")]
+use ckb_types::{packed, prelude::*};
+
+macro_rules! serde_hex_string {
+    ($name: ident, $encode: path) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&$encode(&self.0))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use std::str::FromStr;
+
+                let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Self::from_str(&s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Implement `arbitrary::Arbitrary` for a newtype from its inner value, or from
+/// `$source` converted into the inner value.
+macro_rules! arbitrary_newtype {
+    ($name: ident) => {
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self(arbitrary::Arbitrary::arbitrary(u)?))
+            }
+        }
+    };
+    ($name: ident, $source: ty) => {
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self(
+                    <$source as arbitrary::Arbitrary>::arbitrary(u)?.into(),
+                ))
+            }
+        }
+    };
+}
 
 macro_rules! graphql_primitive {
     ($name: ident, $type_: ty) => {
-        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
         pub struct $name(pub $type_);
 
+        #[cfg(feature = "ckb")]
         impl From<ckb_types::packed::$name> for $name {
             fn from(item: ckb_types::packed::$name) -> Self {
                 use ckb_types::prelude::Unpack;
@@ -21,6 +121,7 @@ macro_rules! graphql_primitive {
             }
         }
 
+        #[cfg(feature = "ckb")]
         impl From<$name> for ckb_types::packed::$name {
             fn from(item: $name) -> Self {
                 use ckb_types::prelude::Pack;
@@ -31,9 +132,16 @@ macro_rules! graphql_primitive {
         impl std::str::FromStr for $name {
             type Err = crate::error::Error;
 
+            /// Parse the 0x-prefixed hex string. An empty hex body `"0x"` is
+            /// parsed as zero, while a string without the prefix is rejected.
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 let s = crate::hex::clean_0x(s)?;
-                Ok(Self(<$type_>::from_str_radix(&s, 16)?))
+
+                if s.is_empty() {
+                    return Ok(Self::default());
+                }
+
+                Ok(Self(<$type_>::from_str_radix(s, 16)?))
             }
         }
 
@@ -42,62 +150,24 @@ macro_rules! graphql_primitive {
                 Self(value)
             }
 
-            #[cfg(test)]
-            pub fn random() -> Self {
-                Self::new(rand::random())
-            }
-        }
-
-        #[async_graphql::Scalar]
-        impl async_graphql::ScalarType for $name {
-            fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+            /// Parse the 0x-prefixed hex string, rejecting leading zeros such
+            /// as `"0x0f"` and the empty `"0x"` as the CKB JSON-RPC does. Only
+            /// `"0x0"` may start with a zero.
+            pub fn from_str_canonical(s: &str) -> Result<Self, crate::error::Error> {
                 use std::str::FromStr;
 
-                if let async_graphql::Value::String(value) = &value {
-                    return Self::from_str(&value)
-                        .map_err(|e| async_graphql::InputValueError::custom(e));
-                }
-                Err(async_graphql::InputValueError::expected_type(value))
-            }
-
-            fn to_value(&self) -> async_graphql::Value {
-                async_graphql::Value::String(crate::hex::hex_uint(self.0))
-            }
-        }
-    };
-
-    ($name: ident, $len: expr) => {
-        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
-        pub struct $name(pub [u8; $len]);
-
-        impl From<ckb_types::$name> for $name {
-            fn from(item: ckb_types::$name) -> Self {
-                Self(item.0)
+                crate::hex::check_canonical_uint(s)?;
+                Self::from_str(s)
             }
-        }
 
-        impl From<$name> for ckb_types::$name {
-            fn from(item: $name) -> Self {
-                Self(item.0)
+            #[cfg(test)]
+            pub fn random() -> Self {
+                Self::new(rand::random())
             }
         }
 
-        impl std::str::FromStr for $name {
-            type Err = crate::error::Error;
-
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                let bytes = crate::hex::hex_decode(s)?;
-
-                if bytes.len() != $len {
-                    return Err(crate::error::Error::ParseBytes);
-                }
-
-                let mut array = [0u8; $len];
-                array.copy_from_slice(&bytes);
-
-                Ok(Self(array))
-            }
-        }
+        serde_hex_string!($name, crate::hex::hex_uint);
+        arbitrary_newtype!($name);
 
         #[async_graphql::Scalar]
         impl async_graphql::ScalarType for $name {
@@ -112,26 +182,13 @@ macro_rules! graphql_primitive {
             }
 
             fn to_value(&self) -> async_graphql::Value {
-                async_graphql::Value::String(crate::hex::hex_encode(&self.0))
-            }
-        }
-
-        impl $name {
-            pub fn new(array: [u8; $len]) -> Self {
-                Self(array)
-            }
-
-            #[cfg(test)]
-            pub fn random() -> Self {
-                let mut array = [0u8; $len];
-                array.iter_mut().for_each(|x| *x = rand::random());
-                Self(array)
+                async_graphql::Value::String(crate::hex::hex_uint(self.0))
             }
         }
     };
 
     ($name: ident) => {
-        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
         pub struct $name(pub bytes::Bytes);
 
         impl From<Vec<u8>> for $name {
@@ -146,6 +203,20 @@ macro_rules! graphql_primitive {
             }
         }
 
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = [u8];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
         impl std::str::FromStr for $name {
             type Err = crate::error::Error;
 
@@ -156,6 +227,9 @@ macro_rules! graphql_primitive {
             }
         }
 
+        serde_hex_string!($name, crate::hex::hex_encode);
+        arbitrary_newtype!($name, Vec<u8>);
+
         #[async_graphql::Scalar]
         impl async_graphql::ScalarType for $name {
             fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
@@ -242,20 +316,639 @@ pub type Timestamp = Uint64;
 /// This is a 32-bit unsigned integer type encoded as the 0x-prefixed hex
 /// string. See examples of [Uint32](type.Uint32.html#examples).
 pub type Version = Uint32;
+/// The compact form of a 256-bit difficulty target. The highest byte is the
+/// size of the target in bytes and the lower 24 bits are the most significant
+/// bytes of the target.
+///
+/// This is a 32-bit unsigned integer type encoded as the 0x-prefixed hex
+/// string. See examples of [Uint32](type.Uint32.html#examples).
+pub type CompactTarget = Uint32;
+/// The proof-of-work nonce in the block header.
+///
+/// Miners search for a nonce which makes the Eaglesong hash of the header
+/// within the target of `compact_target`.
+///
+/// This is a 128-bit unsigned integer type encoded as the 0x-prefixed hex
+/// string.
+///
+/// ## Examples
+///
+/// ```
+/// use ckb_graphql_types::Nonce;
+///
+/// let nonce: Nonce = "0x7f6a8c1e2d3b4a59".parse().unwrap();
+/// assert_eq!(nonce.0, 0x7f6a8c1e2d3b4a59);
+/// ```
+pub type Nonce = Uint128;
+
+/// The epoch number of an [EpochNumberWithFraction], which is stored in the
+/// lowest 24 bits.
+pub fn epoch_number(epoch: &EpochNumberWithFraction) -> u64 {
+    epoch.0 & 0xff_ffff
+}
+
+/// The index of the block in its epoch, which is stored in the 16 bits above
+/// the epoch number.
+pub fn epoch_index(epoch: &EpochNumberWithFraction) -> u64 {
+    (epoch.0 >> 24) & 0xffff
+}
+
+/// The number of blocks in the epoch, which is stored in the 16 bits above
+/// the block index.
+pub fn epoch_length(epoch: &EpochNumberWithFraction) -> u64 {
+    (epoch.0 >> 40) & 0xffff
+}
+
+/// Combine the components into an [EpochNumberWithFraction], computing
+/// `number | (index << 24) | (length << 40)`.
+///
+/// Returns [Error::InvalidEpoch](error::Error) if `number` doesn't fit in 24
+/// bits, or `index` or `length` doesn't fit in 16 bits.
+pub fn epoch_with_fraction(
+    number: u64,
+    index: u64,
+    length: u64,
+) -> Result<EpochNumberWithFraction, error::Error> {
+    if number > 0xff_ffff || index > 0xffff || length > 0xffff {
+        return Err(error::Error::InvalidEpoch {
+            number,
+            index,
+            length,
+        });
+    }
+
+    Ok(EpochNumberWithFraction::new(
+        number | (index << 24) | (length << 40),
+    ))
+}
+
+/// Parse a list of 0x-prefixed hex strings, such as `header_deps` received
+/// from untrusted input, into [H256] hashes.
+///
+/// The error reports the index of the first malformed item.
+pub fn parse_h256_list(list: &[String]) -> Result<Vec<H256>, error::Error> {
+    list.iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.parse().map_err(|e| error::Error::InvalidListItem {
+                index,
+                source: Box::new(e),
+            })
+        })
+        .collect()
+}
+
+/// Encode a list of [H256] hashes as 0x-prefixed hex strings, the inverse of
+/// [parse_h256_list].
+pub fn encode_h256_list(list: &[H256]) -> Vec<String> {
+    list.iter().map(hex::hex_encode).collect()
+}
 
 graphql_primitive!(Uint32, u32);
 graphql_primitive!(Uint64, u64);
 graphql_primitive!(Uint128, u128);
-graphql_primitive!(H160, 20);
-graphql_primitive!(H256, 32);
 graphql_primitive!(GraphqlBytes);
 
+/// The fixed-length bytes encoded as the 0x-prefixed hex string of exactly `N`
+/// bytes, such as a 48-byte BLS public key.
+///
+/// It is named `FixedBytes{N}` in the schema. [H160], [H256] and
+/// [ProposalShortId] are newtypes built on it, which keep their own GraphQL
+/// scalar names.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedBytes<N> {
+    pub const fn new(array: [u8; N]) -> Self {
+        Self(array)
+    }
+
+    #[cfg(test)]
+    pub fn random() -> Self {
+        let mut array = [0u8; N];
+        array.iter_mut().for_each(|x| *x = rand::random());
+        Self(array)
+    }
+}
+
+impl<const N: usize> Default for FixedBytes<N> {
+    fn default() -> Self {
+        Self([0u8; N])
+    }
+}
+
+impl<const N: usize> std::str::FromStr for FixedBytes<N> {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::hex_decode(s)?;
+
+        if bytes.len() != N {
+            return Err(error::Error::ParseBytes);
+        }
+
+        let mut array = [0u8; N];
+        array.copy_from_slice(&bytes);
+
+        Ok(Self(array))
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> std::ops::Deref for FixedBytes<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
+    type Error = error::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let array = value.try_into().map_err(|_| error::Error::InvalidLength {
+            expected: N,
+            actual:   value.len(),
+        })?;
+
+        Ok(Self(array))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for FixedBytes<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::hex_encode(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for FixedBytes<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for FixedBytes<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.arbitrary()?))
+    }
+}
+
+impl<const N: usize> async_graphql::TypeName for FixedBytes<N> {
+    fn type_name() -> std::borrow::Cow<'static, str> {
+        format!("FixedBytes{}", N).into()
+    }
+}
+
+#[async_graphql::Scalar(name_type)]
+impl<const N: usize> async_graphql::ScalarType for FixedBytes<N> {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        use std::str::FromStr;
+
+        if let async_graphql::Value::String(value) = &value {
+            return Self::from_str(value).map_err(async_graphql::InputValueError::custom);
+        }
+        Err(async_graphql::InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(hex::hex_encode(self.0))
+    }
+}
+
+/// Define a fixed-length bytes newtype delegating the parsing and the hex
+/// encoding to [FixedBytes].
+macro_rules! fixed_bytes_newtype {
+    ($(#[$meta: meta])* $name: ident, $len: expr) => {
+        $(#[$meta])*
+        #[derive(Default, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            pub const fn new(array: [u8; $len]) -> Self {
+                Self(array)
+            }
+
+            #[cfg(test)]
+            pub fn random() -> Self {
+                FixedBytes::<$len>::random().into()
+            }
+        }
+
+        impl From<FixedBytes<$len>> for $name {
+            fn from(value: FixedBytes<$len>) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl From<$name> for FixedBytes<$len> {
+            fn from(value: $name) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = error::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                FixedBytes::<$len>::from_str(s).map(Into::into)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = [u8];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = error::Error;
+
+            fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+                FixedBytes::<$len>::try_from(value).map(Into::into)
+            }
+        }
+
+        serde_hex_string!($name, crate::hex::hex_encode);
+        arbitrary_newtype!($name);
+
+        #[async_graphql::Scalar]
+        impl async_graphql::ScalarType for $name {
+            fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+                <FixedBytes<$len> as async_graphql::ScalarType>::parse(value)
+                    .map(Into::into)
+                    .map_err(async_graphql::InputValueError::propagate)
+            }
+
+            fn to_value(&self) -> async_graphql::Value {
+                async_graphql::ScalarType::to_value(&FixedBytes(self.0))
+            }
+        }
+    };
+}
+
+fixed_bytes_newtype!(
+    /// The 20-byte fixed-length binary encoded as a 0x-prefixed hex string.
+    H160,
+    20
+);
+fixed_bytes_newtype!(
+    /// The 32-byte fixed-length binary encoded as a 0x-prefixed hex string.
+    H256,
+    32
+);
+fixed_bytes_newtype!(
+    /// The 10-byte fixed-length binary encoded as a 0x-prefixed hex string,
+    /// which identifies a transaction proposal.
+    ProposalShortId,
+    10
+);
+
+macro_rules! fixed_hash_conversion {
+    ($($name: ident)*) => {
+        $(
+            #[cfg(feature = "ckb")]
+            impl From<ckb_types::$name> for $name {
+                fn from(item: ckb_types::$name) -> Self {
+                    Self(item.0)
+                }
+            }
+
+            #[cfg(feature = "ckb")]
+            impl From<$name> for ckb_types::$name {
+                fn from(item: $name) -> Self {
+                    Self(item.0)
+                }
+            }
+        )*
+    };
+}
+
+fixed_hash_conversion!(H160 H256);
+
+/// A 256-bit unsigned integer stored as big-endian bytes.
+///
+/// This is encoded as the 0x-prefixed hex string without leading zeros, the
+/// same as the other uint scalars.
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Uint256(pub [u8; 32]);
+
+impl Uint256 {
+    pub fn new(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+
+    /// Parse the 0x-prefixed hex string, rejecting leading zeros. See
+    /// [Uint64::from_str_canonical](struct.Uint64.html#method.
+    /// from_str_canonical).
+    pub fn from_str_canonical(s: &str) -> Result<Self, error::Error> {
+        use std::str::FromStr;
+
+        hex::check_canonical_uint(s)?;
+        Self::from_str(s)
+    }
+
+    #[cfg(test)]
+    pub fn random() -> Self {
+        let mut array = [0u8; 32];
+        array.iter_mut().for_each(|x| *x = rand::random());
+        Self(array)
+    }
+}
+
+impl std::str::FromStr for Uint256 {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = hex::clean_0x(s)?;
+
+        if s.len() > 64 {
+            return Err(error::Error::ParseBytes);
+        }
+
+        let padded = format!("{:0>64}", s);
+        let mut array = [0u8; 32];
+        faster_hex::hex_decode(padded.as_bytes(), &mut array)?;
+
+        Ok(Self(array))
+    }
+}
+
+serde_hex_string!(Uint256, crate::hex::hex_be_uint);
+arbitrary_newtype!(Uint256);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for Uint256 {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        use std::str::FromStr;
+
+        if let async_graphql::Value::String(value) = &value {
+            return Self::from_str(value).map_err(async_graphql::InputValueError::custom);
+        }
+        Err(async_graphql::InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(crate::hex::hex_be_uint(self.0))
+    }
+}
+
+#[cfg(feature = "ckb")]
+impl From<ckb_types::U256> for Uint256 {
+    fn from(value: ckb_types::U256) -> Self {
+        let packed_value: packed::Uint256 = value.pack();
+        let mut array = [0u8; 32];
+        array.copy_from_slice(packed_value.as_slice());
+        array.reverse();
+        Self(array)
+    }
+}
+
+#[cfg(feature = "ckb")]
+impl From<Uint256> for ckb_types::U256 {
+    fn from(value: Uint256) -> Self {
+        let mut array = value.0;
+        array.reverse();
+        packed::Uint256::from_slice(&array)
+            .expect("uint256 must be 32 bytes")
+            .unpack()
+    }
+}
+
+#[cfg(feature = "ckb")]
 impl From<packed::Byte32> for H256 {
     fn from(value: packed::Byte32) -> Self {
         value.unpack().into()
     }
 }
 
+#[cfg(feature = "ckb")]
+impl From<H256> for packed::Byte32 {
+    fn from(value: H256) -> Self {
+        value.0.pack()
+    }
+}
+
+/// Add two capacities, returning `None` on overflow.
+pub fn capacity_checked_add(lhs: &Capacity, rhs: &Capacity) -> Option<Capacity> {
+    lhs.0.checked_add(rhs.0).map(Capacity::new)
+}
+
+/// Subtract `rhs` from `lhs`, returning `None` on underflow.
+pub fn capacity_checked_sub(lhs: &Capacity, rhs: &Capacity) -> Option<Capacity> {
+    lhs.0.checked_sub(rhs.0).map(Capacity::new)
+}
+
+/// The ratio of `occupied` to `capacity`, such as how much of a cell capacity
+/// is taken by its occupied storage. Returns `None` if `capacity` is zero.
+pub fn capacity_occupied_ratio(capacity: &Capacity, occupied: &Capacity) -> Option<f64> {
+    if capacity.0 == 0 {
+        return None;
+    }
+
+    Some(occupied.0 as f64 / capacity.0 as f64)
+}
+
+impl GraphqlBytes {
+    /// The number of bytes this data occupies in a cell.
+    ///
+    /// Cell data is stored as a flat blob, so this is the plain byte length
+    /// without the molecule length header. Use this in occupied capacity
+    /// computations.
+    pub fn occupied_bytes(&self) -> u64 {
+        self.0.len() as u64
+    }
+
+    /// The number of bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// A new `GraphqlBytes` sharing the bytes in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn slice(&self, range: impl std::ops::RangeBounds<usize>) -> GraphqlBytes {
+        GraphqlBytes(self.0.slice(range))
+    }
+
+    /// Parse the 0x-prefixed hex string, rejecting more than `max` bytes with
+    /// [Error::TooLong](error::Error::TooLong).
+    ///
+    /// The length is checked before decoding, so an oversized input from an
+    /// untrusted client is rejected without allocating for it.
+    pub fn parse_with_max(s: &str, max: usize) -> Result<Self, error::Error> {
+        let actual = s.len().saturating_sub(2) / 2;
+        if actual > max {
+            return Err(error::Error::TooLong { max, actual });
+        }
+
+        s.parse()
+    }
+
+    /// The scalar value with the hex truncated to the first `max_bytes` bytes
+    /// followed by `...`, such as for logging large witnesses.
+    ///
+    /// The truncated output is not parseable as `GraphqlBytes`. Bytes within
+    /// the cap are rendered the same as `to_value`.
+    pub fn to_value_truncated(&self, max_bytes: usize) -> async_graphql::Value {
+        if self.0.len() <= max_bytes {
+            return async_graphql::ScalarType::to_value(self);
+        }
+
+        async_graphql::Value::String(hex::hex_encode(&self.0[..max_bytes]) + "...")
+    }
+
+    /// Copy the bytes into a fixed array, such as cell data holding exactly a
+    /// 32-byte state root. Returns [Error::InvalidLength](error::Error) if the
+    /// length is not `N`.
+    pub fn to_array<const N: usize>(&self) -> Result<[u8; N], error::Error> {
+        self.0
+            .as_ref()
+            .try_into()
+            .map_err(|_| error::Error::InvalidLength {
+                expected: N,
+                actual:   self.0.len(),
+            })
+    }
+
+    /// Serialize as the molecule `Bytes`, which is the 4-byte little-endian
+    /// length header followed by the bytes.
+    ///
+    /// The JSON and GraphQL hex representation has no length header.
+    pub fn to_molecule(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(4 + self.0.len());
+        buf.extend_from_slice(&(self.0.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&self.0);
+        buf
+    }
+
+    /// Parse the molecule `Bytes` produced by
+    /// [to_molecule](#method.to_molecule).
+    ///
+    /// Returns [Error::InvalidLength](error::Error) if `slice` is shorter
+    /// than the header or the length header disagrees with the data.
+    pub fn from_molecule(slice: &[u8]) -> Result<Self, error::Error> {
+        let header = slice.get(..4).ok_or(error::Error::InvalidLength {
+            expected: 4,
+            actual:   slice.len(),
+        })?;
+        let len = u32::from_le_bytes(header.try_into().expect("the header is 4 bytes")) as usize;
+
+        if slice.len() - 4 != len {
+            return Err(error::Error::InvalidLength {
+                expected: 4 + len,
+                actual:   slice.len(),
+            });
+        }
+
+        Ok(GraphqlBytes(bytes::Bytes::copy_from_slice(&slice[4..])))
+    }
+}
+
+impl ProposalShortId {
+    /// The proposal short id of a transaction, which is the first 10 bytes of
+    /// the transaction hash.
+    pub fn from_tx_hash(tx_hash: &H256) -> ProposalShortId {
+        let mut array = [0u8; 10];
+        array.copy_from_slice(&tx_hash.0[..10]);
+        ProposalShortId::new(array)
+    }
+}
+
+#[cfg(feature = "ckb")]
+impl From<packed::ProposalShortId> for ProposalShortId {
+    fn from(value: packed::ProposalShortId) -> Self {
+        let mut array = [0u8; 10];
+        array.copy_from_slice(value.as_slice());
+        Self(array)
+    }
+}
+
+#[cfg(feature = "ckb")]
+impl From<ProposalShortId> for packed::ProposalShortId {
+    fn from(value: ProposalShortId) -> Self {
+        Self::from_slice(&value.0).expect("proposal short id must be 10 bytes")
+    }
+}
+
+/// Convert from a reference of the packed type by cloning it, which avoids the
+/// `.clone()` at call sites that only borrow the packed value.
+macro_rules! packed_ref_conversion {
+    ($($packed: ident => $name: ident),*) => {
+        $(
+            #[cfg(feature = "ckb")]
+            impl From<&packed::$packed> for $name {
+                fn from(value: &packed::$packed) -> Self {
+                    value.clone().into()
+                }
+            }
+        )*
+    };
+}
+
+packed_ref_conversion!(
+    Header => Header,
+    UncleBlock => UncleBlock,
+    Block => Block,
+    Transaction => TransactionView,
+    Script => Script,
+    CellOutput => CellOutput,
+    OutPoint => OutPoint,
+    CellInput => CellInput,
+    CellDep => CellDep,
+    WitnessArgs => WitnessArgs
+);
+
+/// Query `fields` of `value` through a schema, which tests the resolvers of
+/// complex objects since they cannot be called directly.
+#[cfg(all(test, feature = "ckb"))]
+pub(crate) async fn query_object<T>(value: T, fields: &str) -> serde_json::Value
+where
+    T: async_graphql::OutputType + 'static,
+{
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    struct Query<T>(T);
+
+    #[Object]
+    impl<T: async_graphql::OutputType> Query<T> {
+        async fn value(&self) -> &T {
+            &self.0
+        }
+    }
+
+    let schema = Schema::new(Query(value), EmptyMutation, EmptySubscription);
+    let res = schema.execute(format!("{{ value {{ {fields} }} }}")).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    res.data.into_json().unwrap()["value"].take()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,5 +969,341 @@ mod tests {
 		};
 	}
 
-    test_graphql_primitive!(Uint32 Uint64 Uint128 H160 H256 GraphqlBytes);
+    #[test]
+    fn test_epoch_number_with_fraction() {
+        let epoch = EpochNumberWithFraction::new(1_099_520_939_130_930);
+
+        assert_eq!(epoch_number(&epoch), 50);
+        assert_eq!(epoch_index(&epoch), 11555 - 11000);
+        assert_eq!(epoch_length(&epoch), 1000);
+        assert_eq!(epoch_with_fraction(50, 11555 - 11000, 1000).unwrap(), epoch);
+
+        for (number, index, length) in [(1 << 24, 0, 1), (0, 1 << 16, 1), (0, 0, 1 << 16)] {
+            assert!(matches!(
+                epoch_with_fraction(number, index, length),
+                Err(error::Error::InvalidEpoch { .. })
+            ));
+        }
+        let max = epoch_with_fraction(0xff_ffff, 0xffff, 0xffff).unwrap();
+        assert_eq!(epoch_number(&max), 0xff_ffff);
+        assert_eq!(epoch_index(&max), 0xffff);
+        assert_eq!(epoch_length(&max), 0xffff);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let hash = H256::random();
+        assert_eq!(H256::try_from(&hash.0[..]).unwrap(), hash);
+
+        let hash = H160::random();
+        assert_eq!(H160::try_from(&hash.0[..]).unwrap(), hash);
+
+        assert!(matches!(
+            H256::try_from(&[0u8; 31][..]),
+            Err(error::Error::InvalidLength {
+                expected: 32,
+                actual:   31,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        fn takes(value: impl AsRef<[u8]>) -> usize {
+            value.as_ref().len()
+        }
+
+        let data = GraphqlBytes::random();
+        assert_eq!(takes(H160::random()), 20);
+        assert_eq!(takes(H256::random()), 32);
+        assert_eq!(takes(ProposalShortId::random()), 10);
+        assert_eq!(takes(data.clone()), data.0.len());
+        assert_eq!(&data[..4], &data.0[..4]);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Uint64::new(1) < Uint64::new(2));
+        assert!(Uint128::new(u128::MAX) > Uint128::new(0));
+
+        let mut low = [0u8; 32];
+        let mut high = [0u8; 32];
+        low[31] = 0xff;
+        high[0] = 0x01;
+        assert!(H256::new(low) < H256::new(high));
+        assert_eq!(
+            H256::new(low).cmp(&H256::new(low)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_capacity_arithmetic() {
+        let max = Capacity::new(u64::MAX);
+        let one = Capacity::new(1);
+
+        assert_eq!(capacity_checked_add(&max, &one), None);
+        assert_eq!(
+            capacity_checked_sub(&max, &one),
+            Some(Capacity::new(u64::MAX - 1))
+        );
+        assert_eq!(capacity_checked_sub(&one, &max), None);
+        assert_eq!(capacity_checked_add(&one, &one), Some(Capacity::new(2)));
+
+        assert_eq!(
+            capacity_occupied_ratio(&Capacity::new(4), &one),
+            Some(0.25)
+        );
+        assert_eq!(capacity_occupied_ratio(&Capacity::default(), &one), None);
+    }
+
+    #[test]
+    fn test_uint256() {
+        use std::str::FromStr;
+
+        use async_graphql::ScalarType;
+
+        let mut array = [0u8; 32];
+        array[30] = 0x01;
+        array[31] = 0xff;
+
+        assert_eq!(Uint256::from_str("0x1ff").unwrap(), Uint256::new(array));
+        assert_eq!(
+            Uint256::new(array).to_value(),
+            async_graphql::Value::String("0x1ff".to_owned())
+        );
+        assert_eq!(
+            Uint256::default().to_value(),
+            async_graphql::Value::String("0x0".to_owned())
+        );
+        assert!(Uint256::from_str(&format!("0x1{}", "0".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn test_from_str_canonical() {
+        assert_eq!(Uint64::from_str_canonical("0x0").unwrap(), Uint64::new(0));
+        assert_eq!(Uint64::from_str_canonical("0xf").unwrap(), Uint64::new(15));
+        assert!(matches!(
+            Uint64::from_str_canonical("0x0f"),
+            Err(error::Error::NonCanonicalHex(_))
+        ));
+        assert!(matches!(
+            Uint256::from_str_canonical("0x00"),
+            Err(error::Error::NonCanonicalHex(_))
+        ));
+        assert!(matches!(
+            Uint64::from_str_canonical("0x"),
+            Err(error::Error::NonCanonicalHex(_))
+        ));
+        assert!(matches!(
+            Uint256::from_str_canonical("0x"),
+            Err(error::Error::NonCanonicalHex(_))
+        ));
+
+        use std::str::FromStr;
+        assert_eq!(Uint64::from_str("0x0f").unwrap(), Uint64::new(15));
+    }
+
+    #[test]
+    fn test_empty_uint_hex() {
+        use std::str::FromStr;
+
+        assert_eq!(Uint64::from_str("0x").unwrap(), Uint64::new(0));
+        assert_eq!(Uint64::from_str("0x0").unwrap(), Uint64::new(0));
+        assert_eq!(Uint32::from_str("0X").unwrap(), Uint32::new(0));
+        assert!(matches!(Uint64::from_str(""), Err(error::Error::HexPrefix)));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use async_graphql::ScalarType;
+
+        macro_rules! check {
+            ($u: expr, $($type_: ty)*) => {
+                $(
+                    let case = <$type_>::arbitrary($u).unwrap();
+                    assert_eq!(<$type_>::parse(case.to_value()).unwrap(), case);
+                )*
+            };
+        }
+
+        let data = (0..1024).map(|_| rand::random::<u8>()).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+        check!(&mut u, Uint32 Uint64 Uint128 Uint256 H160 H256 ProposalShortId GraphqlBytes);
+    }
+
+    #[test]
+    fn test_fixed_bytes() {
+        use std::str::FromStr;
+
+        use async_graphql::{InputType, ScalarType};
+
+        let key = FixedBytes::<48>::random();
+        let value = ScalarType::to_value(&key);
+        assert_eq!(<FixedBytes<48> as ScalarType>::parse(value).unwrap(), key);
+        assert_eq!(<FixedBytes<48> as InputType>::type_name(), "FixedBytes48");
+        assert!(matches!(
+            FixedBytes::<48>::from_str(&hex::hex_encode([0u8; 32])),
+            Err(error::Error::ParseBytes)
+        ));
+
+        let hash = H256([1u8; 32]);
+        let H256(inner) = hash.clone();
+        assert_eq!(inner, [1u8; 32]);
+        assert_eq!(<H256 as InputType>::type_name(), "H256");
+        assert_eq!(<H160 as InputType>::type_name(), "H160");
+        assert_eq!(<ProposalShortId as InputType>::type_name(), "ProposalShortId");
+        assert_eq!(
+            ScalarType::to_value(&hash),
+            ScalarType::to_value(&FixedBytes(hash.0))
+        );
+        assert_eq!(
+            ScalarType::to_value(&hash),
+            async_graphql::Value::String(format!("0x{}", "01".repeat(32)))
+        );
+        assert_eq!(H256::from(FixedBytes::<32>::from(hash.clone())), hash);
+        #[cfg(feature = "ckb")]
+        assert_eq!(H256::from(ckb_types::H256::from(hash.clone())), hash);
+    }
+
+    #[test]
+    fn test_bytes_slice() {
+        let data = GraphqlBytes::from(b"hello world".to_vec());
+
+        assert_eq!(data.len(), 11);
+        assert!(!data.is_empty());
+        assert_eq!(data.slice(6..), GraphqlBytes::from(b"world".to_vec()));
+        assert_eq!(data.slice(..5).len(), 5);
+        assert!(data.slice(5..5).is_empty());
+    }
+
+    #[cfg(feature = "ckb")]
+    #[test]
+    fn test_byte32_round_trip() {
+        let byte32 = H256::random().0.pack();
+        let hash = H256::from(byte32.clone());
+
+        assert_eq!(hash.0, byte32.raw_data()[..]);
+        assert_eq!(packed::Byte32::from(hash), byte32);
+    }
+
+    #[test]
+    fn test_parse_with_max() {
+        let hex = hex::hex_encode([0xabu8; 20]);
+
+        assert_eq!(GraphqlBytes::parse_with_max(&hex, 21).unwrap().len(), 20);
+        assert_eq!(GraphqlBytes::parse_with_max(&hex, 20).unwrap().len(), 20);
+        assert!(matches!(
+            GraphqlBytes::parse_with_max(&hex, 19),
+            Err(error::Error::TooLong {
+                max:    19,
+                actual: 20,
+            })
+        ));
+        assert!(GraphqlBytes::parse_with_max("0x", 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_to_value_truncated() {
+        use async_graphql::{ScalarType, Value};
+
+        let data = GraphqlBytes::from(vec![0xab; 4]);
+        assert_eq!(data.to_value_truncated(4), data.to_value());
+        assert_eq!(data.to_value_truncated(8), data.to_value());
+        assert_eq!(
+            data.to_value_truncated(2),
+            Value::String("0xabab...".to_owned())
+        );
+        assert!(GraphqlBytes::parse(data.to_value_truncated(2)).is_err());
+    }
+
+    #[test]
+    fn test_to_array() {
+        let root = H256::random();
+        let data = GraphqlBytes::from(root.0.to_vec());
+        assert_eq!(data.to_array::<32>().unwrap(), root.0);
+
+        let data = GraphqlBytes::from(vec![0u8; 31]);
+        assert!(matches!(
+            data.to_array::<32>(),
+            Err(error::Error::InvalidLength {
+                expected: 32,
+                actual:   31,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_molecule_bytes() {
+        let empty = GraphqlBytes::default();
+        assert_eq!(empty.to_molecule(), vec![0, 0, 0, 0]);
+        assert_eq!(GraphqlBytes::from_molecule(&[0, 0, 0, 0]).unwrap(), empty);
+
+        let data = GraphqlBytes::from(vec![0xab, 0xcd, 0xef]);
+        let molecule = data.to_molecule();
+        assert_eq!(molecule, vec![3, 0, 0, 0, 0xab, 0xcd, 0xef]);
+        assert_eq!(GraphqlBytes::from_molecule(&molecule).unwrap(), data);
+
+        for (slice, expected) in [
+            (&molecule[..2], 4),
+            (&molecule[..6], 7),
+            (&[1, 0, 0, 0, 1, 2][..], 5),
+        ] {
+            assert!(matches!(
+                GraphqlBytes::from_molecule(slice),
+                Err(error::Error::InvalidLength { expected: e, actual })
+                    if e == expected && actual == slice.len()
+            ));
+        }
+    }
+
+    #[cfg(feature = "ckb")]
+    #[test]
+    fn test_molecule_bytes_matches_packed() {
+        let data = GraphqlBytes::random();
+        let packed: packed::Bytes = data.0.pack();
+        assert_eq!(data.to_molecule(), packed.as_slice());
+    }
+
+    #[test]
+    fn test_h256_list() {
+        let hashes = vec![H256::random(), H256::random()];
+        let encoded = encode_h256_list(&hashes);
+        assert_eq!(parse_h256_list(&encoded).unwrap(), hashes);
+
+        let mixed = vec![encoded[0].clone(), "0x1234".to_owned(), encoded[1].clone()];
+        assert!(matches!(
+            parse_h256_list(&mixed),
+            Err(error::Error::InvalidListItem { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_occupied_bytes() {
+        let data = GraphqlBytes::random();
+        assert_eq!(data.occupied_bytes(), data.0.len() as u64);
+        assert_eq!(GraphqlBytes::default().occupied_bytes(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use async_graphql::ScalarType;
+
+        let hash = H256::random();
+        let json = serde_json::to_value(&hash).unwrap();
+        assert_eq!(
+            async_graphql::Value::from_json(json.clone()).unwrap(),
+            hash.to_value()
+        );
+        assert_eq!(serde_json::from_value::<H256>(json).unwrap(), hash);
+
+        let number = Uint64::new(0x1f);
+        assert_eq!(serde_json::to_string(&number).unwrap(), r#""0x1f""#);
+        assert_eq!(serde_json::from_str::<Uint64>(r#""0x1f""#).unwrap(), number);
+    }
+
+    test_graphql_primitive!(Uint32 Uint64 Uint128 Uint256 H160 H256 ProposalShortId GraphqlBytes);
 }
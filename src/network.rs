@@ -0,0 +1,149 @@
+use async_graphql::Enum;
+
+use crate::{cell::DepType, CellDep, OutPoint, Uint32, H256};
+
+/// The type hash of the default secp256k1 blake160 sighash-all lock script.
+///
+/// The script is deployed in the genesis block, so the type hash is the same
+/// on the mainnet and the testnet.
+pub const SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH: H256 = H256([
+    0x9b, 0xd7, 0xe0, 0x6f, 0x3e, 0xcf, 0x4b, 0xe0, 0xf2, 0xfc, 0xd2, 0x18, 0x8b, 0x23, 0xf1, 0xb9,
+    0xfc, 0xc8, 0x8e, 0x5d, 0x4b, 0x65, 0xa8, 0x63, 0x7b, 0x17, 0x72, 0x3b, 0xbd, 0xa3, 0xcc, 0xe8,
+]);
+
+/// The type hash of the default secp256k1 blake160 multisig-all lock script.
+///
+/// The script is deployed in the genesis block, so the type hash is the same
+/// on the mainnet and the testnet.
+pub const SECP256K1_BLAKE160_MULTISIG_ALL_TYPE_HASH: H256 = H256([
+    0x5c, 0x50, 0x69, 0xeb, 0x08, 0x57, 0xef, 0xc6, 0x5e, 0x1b, 0xca, 0x0c, 0x07, 0xdf, 0x34, 0xc3,
+    0x16, 0x63, 0xb3, 0x62, 0x2f, 0xd3, 0x87, 0x6c, 0x87, 0x63, 0x20, 0xfc, 0x96, 0x34, 0xe2, 0xa8,
+]);
+
+/// The type hash of the anyone-can-pay lock script on the mainnet.
+pub const MAINNET_ACP_TYPE_HASH: H256 = H256([
+    0xd3, 0x69, 0x59, 0x7f, 0xf4, 0x7f, 0x29, 0xfb, 0xc0, 0xd4, 0x7d, 0x2e, 0x37, 0x75, 0x37, 0x0d,
+    0x12, 0x50, 0xb8, 0x51, 0x40, 0xc6, 0x70, 0xe4, 0x71, 0x8a, 0xf7, 0x12, 0x98, 0x3a, 0x23, 0x54,
+]);
+/// The type hash of the anyone-can-pay lock script on the testnet.
+pub const TESTNET_ACP_TYPE_HASH: H256 = H256([
+    0x34, 0x19, 0xa1, 0xc0, 0x9e, 0xb2, 0x56, 0x7f, 0x65, 0x52, 0xee, 0x7a, 0x8e, 0xcf, 0xfd, 0x64,
+    0x15, 0x5c, 0xff, 0xe0, 0xf1, 0x79, 0x6e, 0x6e, 0x61, 0xec, 0x08, 0x8d, 0x74, 0x0c, 0x13, 0x56,
+]);
+
+/// The type hash of the simple UDT type script on the mainnet.
+pub const MAINNET_SUDT_TYPE_HASH: H256 = H256([
+    0x5e, 0x7a, 0x36, 0xa7, 0x7e, 0x68, 0xee, 0xcc, 0x01, 0x3d, 0xfa, 0x2f, 0xe6, 0xa2, 0x3f, 0x3b,
+    0x6c, 0x34, 0x4b, 0x04, 0x00, 0x58, 0x08, 0x69, 0x4a, 0xe6, 0xdd, 0x45, 0xee, 0xa4, 0xcf, 0xd5,
+]);
+/// The type hash of the simple UDT type script on the testnet.
+pub const TESTNET_SUDT_TYPE_HASH: H256 = H256([
+    0xc5, 0xe5, 0xdc, 0xf2, 0x15, 0x92, 0x5f, 0x7e, 0xf4, 0xdf, 0xaf, 0x5f, 0x4b, 0x4f, 0x10, 0x5b,
+    0xc3, 0x21, 0xc0, 0x27, 0x76, 0xd6, 0xe7, 0xd5, 0x2a, 0x1d, 0xb3, 0xfc, 0xd9, 0xd0, 0x11, 0xa4,
+]);
+
+/// The mainnet genesis transaction holding the secp256k1 and multisig dep
+/// groups.
+const MAINNET_GENESIS_DEP_GROUP_TX_HASH: H256 = H256([
+    0x71, 0xa7, 0xba, 0x8f, 0xc9, 0x63, 0x49, 0xfe, 0xa0, 0xed, 0x3a, 0x5c, 0x47, 0x99, 0x2e, 0x3b,
+    0x40, 0x84, 0xb0, 0x31, 0xa4, 0x22, 0x64, 0xa0, 0x18, 0xe0, 0x07, 0x2e, 0x81, 0x72, 0xe4, 0x6c,
+]);
+/// The mainnet genesis cellbase transaction holding the Nervos DAO script.
+const MAINNET_GENESIS_CELLBASE_TX_HASH: H256 = H256([
+    0xe2, 0xfb, 0x19, 0x98, 0x10, 0xd4, 0x9a, 0x4d, 0x8b, 0xee, 0xc5, 0x67, 0x18, 0xba, 0x25, 0x93,
+    0xb6, 0x65, 0xdb, 0x9d, 0x52, 0x29, 0x9a, 0x0f, 0x9e, 0x6e, 0x75, 0x41, 0x6d, 0x73, 0xff, 0x5c,
+]);
+/// The testnet genesis transaction holding the secp256k1 and multisig dep
+/// groups.
+const TESTNET_GENESIS_DEP_GROUP_TX_HASH: H256 = H256([
+    0xf8, 0xde, 0x3b, 0xb4, 0x7d, 0x05, 0x5c, 0xdf, 0x46, 0x0d, 0x93, 0xa2, 0xa6, 0xe1, 0xb0, 0x5f,
+    0x74, 0x32, 0xf9, 0x77, 0x7c, 0x8c, 0x47, 0x4a, 0xbf, 0x4e, 0xec, 0x1d, 0x4a, 0xee, 0x5d, 0x37,
+]);
+/// The testnet genesis cellbase transaction holding the Nervos DAO script.
+const TESTNET_GENESIS_CELLBASE_TX_HASH: H256 = H256([
+    0x8f, 0x8c, 0x79, 0xeb, 0x66, 0x71, 0x70, 0x96, 0x33, 0xfe, 0x6a, 0x46, 0xde, 0x93, 0xc0, 0xfe,
+    0xdc, 0x9c, 0x1b, 0x8a, 0x65, 0x27, 0xa1, 0x8d, 0x39, 0x83, 0x87, 0x95, 0x42, 0x63, 0x5c, 0x9f,
+]);
+
+const fn genesis_dep(tx_hash: H256, index: u32, dep_type: DepType) -> CellDep {
+    CellDep {
+        out_point: OutPoint {
+            tx_hash,
+            index: Uint32(index),
+        },
+        dep_type,
+    }
+}
+
+/// The secp256k1 blake160 sighash-all dep group on the mainnet.
+pub const MAINNET_SECP256K1_BLAKE160_DEP: CellDep =
+    genesis_dep(MAINNET_GENESIS_DEP_GROUP_TX_HASH, 0, DepType::DepGroup);
+/// The secp256k1 blake160 multisig-all dep group on the mainnet.
+pub const MAINNET_SECP256K1_MULTISIG_DEP: CellDep =
+    genesis_dep(MAINNET_GENESIS_DEP_GROUP_TX_HASH, 1, DepType::DepGroup);
+/// The Nervos DAO script cell on the mainnet.
+pub const MAINNET_DAO_DEP: CellDep =
+    genesis_dep(MAINNET_GENESIS_CELLBASE_TX_HASH, 2, DepType::Code);
+/// The secp256k1 blake160 sighash-all dep group on the testnet.
+pub const TESTNET_SECP256K1_BLAKE160_DEP: CellDep =
+    genesis_dep(TESTNET_GENESIS_DEP_GROUP_TX_HASH, 0, DepType::DepGroup);
+/// The secp256k1 blake160 multisig-all dep group on the testnet.
+pub const TESTNET_SECP256K1_MULTISIG_DEP: CellDep =
+    genesis_dep(TESTNET_GENESIS_DEP_GROUP_TX_HASH, 1, DepType::DepGroup);
+/// The Nervos DAO script cell on the testnet.
+pub const TESTNET_DAO_DEP: CellDep =
+    genesis_dep(TESTNET_GENESIS_CELLBASE_TX_HASH, 2, DepType::Code);
+
+/// The CKB network which well-known system scripts are looked up on.
+#[derive(Enum, Default, Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Network {
+    /// The CKB mainnet, Lina.
+    #[default]
+    Mainnet,
+    /// The CKB public testnet, Aggron.
+    Testnet,
+}
+
+impl Network {
+    /// The code hash of the default secp256k1 blake160 sighash-all lock
+    /// script, which is used with the `type` hash type.
+    pub fn secp256k1_blake160_code_hash(&self) -> H256 {
+        match self {
+            Self::Mainnet | Self::Testnet => SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+        }
+    }
+
+    /// The code hash of the anyone-can-pay lock script, which is used with the
+    /// `type` hash type.
+    pub fn acp_code_hash(&self) -> H256 {
+        match self {
+            Self::Mainnet => MAINNET_ACP_TYPE_HASH,
+            Self::Testnet => TESTNET_ACP_TYPE_HASH,
+        }
+    }
+
+    /// The code hash of the simple UDT type script, which is used with the
+    /// `type` hash type.
+    pub fn sudt_code_hash(&self) -> H256 {
+        match self {
+            Self::Mainnet => MAINNET_SUDT_TYPE_HASH,
+            Self::Testnet => TESTNET_SUDT_TYPE_HASH,
+        }
+    }
+
+    /// The cell deps of the system scripts deployed in the genesis block.
+    pub fn system_deps(&self) -> [CellDep; 3] {
+        match self {
+            Self::Mainnet => [
+                MAINNET_SECP256K1_BLAKE160_DEP,
+                MAINNET_SECP256K1_MULTISIG_DEP,
+                MAINNET_DAO_DEP,
+            ],
+            Self::Testnet => [
+                TESTNET_SECP256K1_BLAKE160_DEP,
+                TESTNET_SECP256K1_MULTISIG_DEP,
+                TESTNET_DAO_DEP,
+            ],
+        }
+    }
+}
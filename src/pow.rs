@@ -0,0 +1,104 @@
+use crate::{CompactTarget, Uint256};
+
+/// The maximum 256-bit target, which is returned for overflowed compact
+/// targets.
+pub const MAX_TARGET: Uint256 = Uint256([0xff; 32]);
+
+/// Encode a 256-bit target in the compact form, the same as
+/// `ckb_types::utilities::target_to_compact`.
+///
+/// Only the 3 most significant bytes of the target are kept.
+pub fn target_to_compact(target: &Uint256) -> CompactTarget {
+    let size = target.0.iter().position(|b| *b != 0).map_or(0, |i| 32 - i);
+    let mantissa = if size <= 3 {
+        let low = u32::from_be_bytes([0, target.0[29], target.0[30], target.0[31]]);
+        low << (8 * (3 - size))
+    } else {
+        let i = 32 - size;
+        u32::from_be_bytes([0, target.0[i], target.0[i + 1], target.0[i + 2]])
+    };
+
+    CompactTarget::new(((size as u32) << 24) | mantissa)
+}
+
+/// Expand the compact form into the 256-bit target, the same as
+/// `ckb_types::utilities::compact_to_target`.
+///
+/// The returned flag is `true` if the target overflows 256 bits, in which
+/// case the overflowed bytes are dropped.
+pub fn compact_to_target(compact: &CompactTarget) -> (Uint256, bool) {
+    let exponent = (compact.0 >> 24) as usize;
+    let mut mantissa = compact.0 & 0x00ff_ffff;
+    let mut target = [0u8; 32];
+
+    if exponent <= 3 {
+        mantissa >>= 8 * (3 - exponent);
+        target[28..].copy_from_slice(&mantissa.to_be_bytes());
+    } else {
+        for (k, byte) in mantissa.to_be_bytes()[1..].iter().enumerate() {
+            match (32 + k).checked_sub(exponent) {
+                Some(i) if i < 32 => target[i] = *byte,
+                _ => {}
+            }
+        }
+    }
+
+    (Uint256::new(target), mantissa != 0 && exponent > 32)
+}
+
+/// Expand the compact form into the 256-bit target, returning
+/// [`MAX_TARGET`] if the target overflows 256 bits.
+pub fn compact_to_target_saturating(compact: &CompactTarget) -> Uint256 {
+    match compact_to_target(compact) {
+        (_, true) => MAX_TARGET,
+        (target, false) => target,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_target_round_trip() {
+        for compact in [0x1e083126, 0x1d7fffff, 0x20010000, 0x03123456, 0x01120000] {
+            let compact = CompactTarget::new(compact);
+            let (target, overflow) = compact_to_target(&compact);
+
+            assert!(!overflow);
+            assert_eq!(target_to_compact(&target), compact);
+        }
+
+        // The non-normalized 0x1d00ffff has a zero leading mantissa byte.
+        let (target, _) = compact_to_target(&CompactTarget::new(0x1d00ffff));
+        assert_eq!(target_to_compact(&target), CompactTarget::new(0x1cffff00));
+        assert_eq!(target_to_compact(&Uint256::default()), CompactTarget::new(0));
+        assert!(compact_to_target(&CompactTarget::new(0x21010000)).1);
+        assert_eq!(
+            compact_to_target_saturating(&CompactTarget::new(0x21010000)),
+            MAX_TARGET
+        );
+    }
+
+    #[cfg(feature = "ckb")]
+    #[test]
+    fn test_compact_target_matches_ckb_types() {
+        use ckb_types::utilities;
+
+        for _ in 0..10 {
+            let target = Uint256::random();
+            assert_eq!(
+                target_to_compact(&target).0,
+                utilities::target_to_compact(target.clone().into())
+            );
+        }
+
+        for compact in [0x1d00ffff, 0x1e083126, 0x20010000, 0x03123456] {
+            let (target, overflow) = utilities::compact_to_target(compact);
+            assert_eq!(
+                compact_to_target(&CompactTarget::new(compact)),
+                (Uint256::from(target), overflow)
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, SchemaBuilder};
+
+use crate::{
+    Block, BlockEconomicState, CellOutputWithData, CellWithStatus, Duration, Epoch, Network,
+    TransactionInput, TransactionView, UncleBlock, WitnessArgs, H256,
+};
+
+/// Register all the types of this crate in `builder`, including those not
+/// reachable from its query root.
+pub fn register_types<Q, M, S>(builder: SchemaBuilder<Q, M, S>) -> SchemaBuilder<Q, M, S> {
+    builder
+        .register_output_type::<Block>()
+        .register_output_type::<UncleBlock>()
+        .register_output_type::<Epoch>()
+        .register_output_type::<BlockEconomicState>()
+        .register_output_type::<TransactionView>()
+        .register_output_type::<CellOutputWithData>()
+        .register_output_type::<CellWithStatus>()
+        .register_output_type::<WitnessArgs>()
+        .register_output_type::<Network>()
+        .register_output_type::<Duration>()
+        .register_input_type::<TransactionInput>()
+}
+
+struct FingerprintQuery;
+
+#[Object]
+impl FingerprintQuery {
+    /// A placeholder field, the schema root requires at least one field.
+    async fn placeholder(&self) -> bool {
+        true
+    }
+}
+
+/// The CKB blake2b-256 hash of the SDL of a schema with all the types of this
+/// crate registered.
+///
+/// It changes whenever a type, field or description changes, so clients can
+/// store it to detect schema changes across releases.
+pub fn schema_fingerprint() -> H256 {
+    H256::new(ckb_hash::blake2b_256(registered_sdl().as_bytes()))
+}
+
+fn registered_schema() -> Schema<FingerprintQuery, EmptyMutation, EmptySubscription> {
+    register_types(Schema::build(
+        FingerprintQuery,
+        EmptyMutation,
+        EmptySubscription,
+    ))
+    .finish()
+}
+
+fn registered_sdl() -> String {
+    registered_schema().sdl()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_fingerprint() {
+        let fingerprint = schema_fingerprint();
+
+        assert_eq!(schema_fingerprint(), fingerprint);
+        assert_ne!(fingerprint, H256::default());
+    }
+
+    #[test]
+    fn test_enum_value_descriptions() {
+        let sdl = registered_sdl();
+
+        for description in [
+            "code in v0 CKB VM.",
+            "matches script code via cell type script hash.",
+            "code in v1 CKB VM.",
+            "Use the cell itself as the dep cell.",
+            "The cell is a dep group which members are cells.",
+        ] {
+            assert!(sdl.contains(description), "missing {description}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_field_descriptions() {
+        // Introspection only finds the types reachable from the query root, so
+        // the registered but unreachable types are not enough here.
+        struct Query;
+
+        #[Object]
+        impl Query {
+            async fn transaction(&self) -> TransactionView {
+                TransactionView::default()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        for (type_name, descriptions) in [
+            (
+                "TransactionView",
+                &[
+                    "Reserved for future usage. It must equal 0 in current version.",
+                    "An array of cell deps.",
+                    "An array of header deps.",
+                    "An array of input cells.",
+                ][..],
+            ),
+            (
+                "Script",
+                &[
+                    "The hash used to match the script code.",
+                    "Arguments for script.",
+                ][..],
+            ),
+        ] {
+            let query =
+                format!("{{ __type(name: \"{type_name}\") {{ fields {{ description }} }} }}");
+            let response = schema.execute(query).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+            let data = response.data.to_string();
+            for description in descriptions {
+                assert!(data.contains(description), "missing {description}");
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use async_graphql::{Enum, SimpleObject};
+
+use crate::{BlockNumber, EpochNumberWithFraction, Uint64};
+
+const RELATIVE_FLAG: u64 = 1 << 63;
+const METRIC_MASK: u64 = 0b11 << 61;
+const RESERVED_MASK: u64 = 0x1f << 56;
+const VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+
+const METRIC_BLOCK_NUMBER: u64 = 0b00 << 61;
+const METRIC_EPOCH: u64 = 0b01 << 61;
+const METRIC_TIMESTAMP: u64 = 0b10 << 61;
+
+/// The metric used to compare a `since` value against the chain.
+#[derive(Enum, Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SinceMetric {
+    /// The block number.
+    BlockNumber,
+    /// The epoch number with fraction.
+    EpochNumberWithFraction,
+    /// The median timestamp of the previous 37 blocks, in seconds.
+    Timestamp,
+}
+
+/// The decoded `since` field of a [CellInput](struct.CellInput.html).
+///
+/// See the RFC [Transaction valid since](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0017-tx-valid-since/0017-tx-valid-since.md).
+/// The highest bit is the relative flag, the next 2 bits select the metric and
+/// the lowest 56 bits are the value. An all-zero `since` is an absolute block
+/// number 0, which does not restrict the input.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SinceValue {
+    /// The transaction is valid from this block number.
+    AbsoluteBlockNumber(BlockNumber),
+    /// The transaction is valid from this epoch.
+    AbsoluteEpoch(EpochNumberWithFraction),
+    /// The transaction is valid from this median timestamp in seconds.
+    AbsoluteTimestamp(Uint64),
+    /// The input cell must be committed for this number of blocks.
+    RelativeBlockNumber(BlockNumber),
+    /// The input cell must be committed for this number of epochs.
+    RelativeEpoch(EpochNumberWithFraction),
+    /// The input cell must be committed for this number of seconds.
+    RelativeTimestamp(Uint64),
+    /// The raw `since` sets reserved bits or uses the unknown metric `0b11`,
+    /// which CKB rejects.
+    Invalid(Uint64),
+}
+
+impl SinceValue {
+    /// Decode a raw `since` value.
+    pub fn decode(since: u64) -> Self {
+        if since & RESERVED_MASK != 0 {
+            return Self::Invalid(Uint64::new(since));
+        }
+
+        let relative = since & RELATIVE_FLAG != 0;
+        let value = Uint64::new(since & VALUE_MASK);
+        match (relative, since & METRIC_MASK) {
+            (false, METRIC_BLOCK_NUMBER) => Self::AbsoluteBlockNumber(value),
+            (false, METRIC_EPOCH) => Self::AbsoluteEpoch(value),
+            (false, METRIC_TIMESTAMP) => Self::AbsoluteTimestamp(value),
+            (true, METRIC_BLOCK_NUMBER) => Self::RelativeBlockNumber(value),
+            (true, METRIC_EPOCH) => Self::RelativeEpoch(value),
+            (true, METRIC_TIMESTAMP) => Self::RelativeTimestamp(value),
+            _ => Self::Invalid(Uint64::new(since)),
+        }
+    }
+
+    /// Encode into the raw `since` value, the inverse of
+    /// [decode](#method.decode).
+    ///
+    /// Bits of the value above the lowest 56 bits are discarded.
+    pub fn encode(&self) -> u64 {
+        let (flag, metric, value) = match self {
+            Self::AbsoluteBlockNumber(v) => (0, METRIC_BLOCK_NUMBER, v),
+            Self::AbsoluteEpoch(v) => (0, METRIC_EPOCH, v),
+            Self::AbsoluteTimestamp(v) => (0, METRIC_TIMESTAMP, v),
+            Self::RelativeBlockNumber(v) => (RELATIVE_FLAG, METRIC_BLOCK_NUMBER, v),
+            Self::RelativeEpoch(v) => (RELATIVE_FLAG, METRIC_EPOCH, v),
+            Self::RelativeTimestamp(v) => (RELATIVE_FLAG, METRIC_TIMESTAMP, v),
+            Self::Invalid(v) => return v.0,
+        };
+        flag | metric | (value.0 & VALUE_MASK)
+    }
+
+    /// The GraphQL representation, or `None` for an invalid `since`.
+    pub fn fields(&self) -> Option<SinceFields> {
+        let (relative, metric, value) = match self {
+            Self::AbsoluteBlockNumber(v) => (false, SinceMetric::BlockNumber, v),
+            Self::AbsoluteEpoch(v) => (false, SinceMetric::EpochNumberWithFraction, v),
+            Self::AbsoluteTimestamp(v) => (false, SinceMetric::Timestamp, v),
+            Self::RelativeBlockNumber(v) => (true, SinceMetric::BlockNumber, v),
+            Self::RelativeEpoch(v) => (true, SinceMetric::EpochNumberWithFraction, v),
+            Self::RelativeTimestamp(v) => (true, SinceMetric::Timestamp, v),
+            Self::Invalid(_) => return None,
+        };
+        Some(SinceFields {
+            relative,
+            metric,
+            value: value.clone(),
+        })
+    }
+}
+
+/// The structured form of [SinceValue](enum.SinceValue.html) exposed in
+/// GraphQL.
+#[derive(SimpleObject, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SinceFields {
+    /// Whether the value is relative to the block committing the input cell.
+    pub relative: bool,
+    /// The metric the value is compared with.
+    pub metric:   SinceMetric,
+    /// The value in the lowest 56 bits.
+    pub value:    Uint64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_since_metrics() {
+        for (since, expected) in [
+            (0, SinceValue::AbsoluteBlockNumber(Uint64::new(0))),
+            (
+                0x0000_0000_0000_3039,
+                SinceValue::AbsoluteBlockNumber(Uint64::new(12345)),
+            ),
+            (
+                0x2000_0100_0000_0032,
+                SinceValue::AbsoluteEpoch(Uint64::new(0x0100_0000_0032)),
+            ),
+            (
+                0x4000_0000_5e83_d980,
+                SinceValue::AbsoluteTimestamp(Uint64::new(1_585_699_200)),
+            ),
+            (
+                0x8000_0000_0000_0064,
+                SinceValue::RelativeBlockNumber(Uint64::new(100)),
+            ),
+            (
+                0xa000_0000_0000_00b4,
+                SinceValue::RelativeEpoch(Uint64::new(180)),
+            ),
+            (
+                0xc000_0000_0001_5180,
+                SinceValue::RelativeTimestamp(Uint64::new(86400)),
+            ),
+        ] {
+            let value = SinceValue::decode(since);
+            assert_eq!(value, expected);
+            assert_eq!(value.encode(), since);
+            assert!(value.fields().is_some());
+        }
+    }
+
+    #[test]
+    fn test_since_relative_flag() {
+        let absolute = SinceValue::decode(0x2000_0000_0000_0001).fields().unwrap();
+        let relative = SinceValue::decode(0xa000_0000_0000_0001).fields().unwrap();
+
+        assert!(!absolute.relative);
+        assert!(relative.relative);
+        assert_eq!(absolute.metric, relative.metric);
+        assert_eq!(absolute.value, relative.value);
+    }
+
+    #[test]
+    fn test_invalid_since() {
+        for since in [0x6000_0000_0000_0001, 0x0100_0000_0000_0001] {
+            let value = SinceValue::decode(since);
+            assert_eq!(value, SinceValue::Invalid(Uint64::new(since)));
+            assert_eq!(value.encode(), since);
+            assert_eq!(value.fields(), None);
+        }
+    }
+}
@@ -1,12 +1,23 @@
-use async_graphql::SimpleObject;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use async_graphql::{ComplexObject, SimpleObject};
 use ckb_types::{core, packed, prelude::*};
 
-use crate::{CellDep, CellInput, CellOutput, GraphqlBytes, Version, H256};
+use crate::{
+    capacity_checked_add, capacity_checked_sub,
+    cell::{DepType, BYTE_SHANNONS},
+    dedup_out_points,
+    error::Error,
+    parse_dep_group, Capacity, CellDep, CellInput, CellOutput, CellOutputWithData, GraphqlBytes,
+    Network, OutPoint, ProposalShortId, Script, Version, WitnessArgs, H160, H256,
+};
 
 /// The transaction view.
 ///
 /// Refer to RFC [CKB Transaction Structure](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0022-transaction-structure/0022-transaction-structure.md).
 #[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[graphql(complex)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransactionView {
     /// Reserved for future usage. It must equal 0 in current version.
     pub version:      Version,
@@ -45,9 +56,22 @@ pub struct TransactionView {
     /// signature in `witnesses`.
     pub witnesses:    Vec<GraphqlBytes>,
     /// The transaction hash.
+    ///
+    /// This field is derived from the other fields. It is ignored when
+    /// converting back to `packed::Transaction`, which always recomputes the
+    /// hash from the raw transaction.
     pub hash:         H256,
 }
 
+#[ComplexObject]
+impl TransactionView {
+    /// The witness hash, which is the hash of the whole transaction including
+    /// the witnesses.
+    async fn witness_hash(&self) -> H256 {
+        self.calc_witness_hash()
+    }
+}
+
 impl From<packed::Transaction> for TransactionView {
     fn from(value: packed::Transaction) -> Self {
         let raw = value.raw();
@@ -73,9 +97,73 @@ impl From<packed::Transaction> for TransactionView {
     }
 }
 
+/// All fields except `hash` are authoritative. The stored `hash` is discarded,
+/// so a view with a stale hash still converts to the packed transaction
+/// described by its other fields.
 impl From<TransactionView> for packed::Transaction {
     fn from(value: TransactionView) -> Self {
-        let raw = packed::RawTransaction::new_builder()
+        let raw = RawTransactionView {
+            version:      value.version,
+            cell_deps:    value.cell_deps,
+            header_deps:  value.header_deps,
+            inputs:       value.inputs,
+            outputs:      value.outputs,
+            outputs_data: value.outputs_data,
+        };
+
+        packed::Transaction::new_builder()
+            .raw(raw.into())
+            .witnesses(
+                value
+                    .witnesses
+                    .into_iter()
+                    .map(|witness| witness.0.pack())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .build()
+    }
+}
+
+/// The transaction without witnesses and the derived hash, which is the part
+/// hashed into the transaction hash and signed.
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawTransactionView {
+    /// Reserved for future usage. It must equal 0 in current version.
+    pub version:      Version,
+    /// An array of cell deps.
+    pub cell_deps:    Vec<CellDep>,
+    /// An array of header deps.
+    pub header_deps:  Vec<H256>,
+    /// An array of input cells.
+    pub inputs:       Vec<CellInput>,
+    /// An array of output cells.
+    pub outputs:      Vec<CellOutput>,
+    /// Output cells data.
+    pub outputs_data: Vec<GraphqlBytes>,
+}
+
+impl From<packed::RawTransaction> for RawTransactionView {
+    fn from(value: packed::RawTransaction) -> Self {
+        Self {
+            version:      value.version().into(),
+            cell_deps:    value.cell_deps().into_iter().map(Into::into).collect(),
+            header_deps:  value.header_deps().into_iter().map(Into::into).collect(),
+            inputs:       value.inputs().into_iter().map(Into::into).collect(),
+            outputs:      value.outputs().into_iter().map(Into::into).collect(),
+            outputs_data: value
+                .outputs_data()
+                .into_iter()
+                .map(|data| GraphqlBytes(data.unpack()))
+                .collect(),
+        }
+    }
+}
+
+impl From<RawTransactionView> for packed::RawTransaction {
+    fn from(value: RawTransactionView) -> Self {
+        Self::new_builder()
             .version(value.version.into())
             .cell_deps(
                 value
@@ -117,22 +205,15 @@ impl From<TransactionView> for packed::Transaction {
                     .collect::<Vec<_>>()
                     .pack(),
             )
-            .build();
-
-        packed::Transaction::new_builder()
-            .raw(raw)
-            .witnesses(
-                value
-                    .witnesses
-                    .into_iter()
-                    .map(|witness| witness.0.pack())
-                    .collect::<Vec<_>>()
-                    .pack(),
-            )
             .build()
     }
 }
 
+/// `ckb-sdk` builds and signs transactions as `core::TransactionView`, so
+/// this also converts the transactions of the `ckb-sdk` releases depending on
+/// `ckb-types` 0.108, the same version as this crate. Later releases, such as
+/// `ckb-sdk` 3.x on `ckb-types` 0.200, have their own `core::TransactionView`,
+/// so convert them through [TransactionView::from_molecule_bytes] instead.
 impl From<core::TransactionView> for TransactionView {
     fn from(value: core::TransactionView) -> Self {
         let raw = value.data().raw();
@@ -157,3 +238,1381 @@ impl From<core::TransactionView> for TransactionView {
         }
     }
 }
+
+impl TransactionView {
+    /// Check that the transaction version is 0, which is the only version
+    /// accepted by CKB now.
+    ///
+    /// The accepted versions may change with future hardforks.
+    pub fn check_version(&self) -> Result<(), Error> {
+        if self.version.0 != 0 {
+            return Err(Error::UnsupportedVersion(self.version.0));
+        }
+
+        Ok(())
+    }
+
+    /// Check that no header dep appears twice, returning the first repeated
+    /// hash otherwise.
+    pub fn check_distinct_header_deps(&self) -> Result<(), Error> {
+        let mut seen = HashSet::new();
+
+        for header_dep in self.header_deps.iter() {
+            if !seen.insert(header_dep) {
+                return Err(Error::DuplicateHeaderDep(header_dep.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that the `lock` field of the first witness is `expected` bytes
+    /// long, such as 65 bytes for a secp256k1 signature placeholder.
+    ///
+    /// A missing first witness or an absent `lock` counts as 0 bytes.
+    pub fn check_first_witness_lock_len(&self, expected: usize) -> Result<(), Error> {
+        let actual = match self.witnesses.first() {
+            Some(witness) => WitnessArgs::from_molecule_bytes(witness)?.lock_len(),
+            None => 0,
+        };
+
+        if actual != expected {
+            return Err(Error::WrongLockLength { expected, actual });
+        }
+
+        Ok(())
+    }
+
+    /// Check that every output has its data, which is `outputs.len() ==
+    /// outputs_data.len()`.
+    pub fn check_outputs_data(&self) -> Result<(), Error> {
+        if self.outputs.len() != self.outputs_data.len() {
+            return Err(Error::OutputsDataMismatch {
+                outputs:      self.outputs.len(),
+                outputs_data: self.outputs_data.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check the structural invariants a hand-built transaction can break:
+    /// there must be at least one input and one output, and every output must
+    /// have its data.
+    ///
+    /// A cellbase passes since it has a single input with the null out point.
+    pub fn validate_structure(&self) -> Result<(), Error> {
+        if self.inputs.is_empty() {
+            return Err(Error::StructureMismatch("no inputs"));
+        }
+        if self.outputs.is_empty() {
+            return Err(Error::StructureMismatch("no outputs"));
+        }
+
+        self.check_outputs_data()
+    }
+
+    /// Convert into the packed transaction after
+    /// [validate_structure](#method.validate_structure) passes.
+    pub fn into_packed_checked(self) -> Result<packed::Transaction, Error> {
+        self.validate_structure()?;
+        Ok(self.into())
+    }
+
+    /// Deserialize a transaction from JSON and check that `outputs` and
+    /// `outputs_data` have the same length, which the derived `Deserialize`
+    /// can't cross-validate.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_checked(json: &str) -> Result<Self, Error> {
+        let view = serde_json::from_str::<Self>(json)?;
+        view.check_outputs_data()?;
+        Ok(view)
+    }
+
+    /// The transaction as indented JSON, such as for debugging dumps.
+    #[cfg(feature = "serde")]
+    pub fn to_pretty_json(&self) -> String {
+        let value = serde_json::to_value(self).expect("transaction view is always serializable");
+        serde_json::to_string_pretty(&value).expect("json value is always serializable")
+    }
+
+    /// The transaction without witnesses and the derived hash.
+    pub fn raw(&self) -> RawTransactionView {
+        RawTransactionView {
+            version:      self.version.clone(),
+            cell_deps:    self.cell_deps.clone(),
+            header_deps:  self.header_deps.clone(),
+            inputs:       self.inputs.clone(),
+            outputs:      self.outputs.clone(),
+            outputs_data: self.outputs_data.clone(),
+        }
+    }
+
+    /// Serialize the transaction in the molecule format.
+    pub fn to_molecule_bytes(&self) -> Vec<u8> {
+        packed::Transaction::from(self.clone()).as_slice().to_vec()
+    }
+
+    /// Serialize only the `RawTransaction` part in the molecule format,
+    /// without witnesses.
+    ///
+    /// This is the payload hashed into the transaction hash, which external
+    /// signers such as hardware wallets hash themselves.
+    pub fn raw_molecule_bytes(&self) -> Vec<u8> {
+        packed::RawTransaction::from(self.raw()).as_slice().to_vec()
+    }
+
+    /// Deserialize a transaction from the molecule format. The hash is
+    /// computed from the decoded transaction.
+    pub fn from_molecule_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(packed::Transaction::from_slice(bytes)?.into())
+    }
+
+    /// Build a transaction from the molecule encoded `RawTransaction` and the
+    /// witnesses transmitted separately.
+    pub fn from_raw_and_witnesses(raw: &[u8], witnesses: &[&[u8]]) -> Result<Self, Error> {
+        let raw = packed::RawTransaction::from_slice(raw)?;
+        let witnesses = witnesses
+            .iter()
+            .map(|witness| witness.pack())
+            .collect::<Vec<packed::Bytes>>();
+
+        Ok(packed::Transaction::new_builder()
+            .raw(raw)
+            .witnesses(witnesses.pack())
+            .build()
+            .into())
+    }
+
+    /// Compute the transaction hash from the current fields, ignoring the
+    /// stored `hash`.
+    pub fn calc_tx_hash(&self) -> H256 {
+        packed::RawTransaction::from(self.raw())
+            .calc_tx_hash()
+            .into()
+    }
+
+    /// Compute the witness hash from the current fields, which covers the
+    /// witnesses as well as the raw transaction.
+    pub fn calc_witness_hash(&self) -> H256 {
+        packed::Transaction::from(self.clone())
+            .calc_witness_hash()
+            .into()
+    }
+
+    /// Compute the transaction hash with `hasher` instead of CKB blake2b,
+    /// such as a mock hasher for reproducible tests.
+    ///
+    /// `hasher` receives the molecule encoded `RawTransaction`, the same
+    /// payload as [raw_molecule_bytes](Self::raw_molecule_bytes).
+    pub fn calc_hash_with<H: Fn(&[u8]) -> [u8; 32]>(&self, hasher: H) -> H256 {
+        H256::new(hasher(&self.raw_molecule_bytes()))
+    }
+
+    /// Compute the message signed by the secp256k1 blake160 sighash-all lock
+    /// for the lock group holding the inputs at `group_input_indices`.
+    ///
+    /// The message is the CKB blake2b hash of the transaction hash, which is
+    /// recomputed so a stale stored `hash` is ignored, then each witness
+    /// prefixed by its length as a little-endian `u64`: first the group's
+    /// first witness with its `lock`, if present, zero-filled as the
+    /// signature placeholder, then the other witnesses of the group, and
+    /// finally the witnesses beyond the number of inputs.
+    ///
+    /// Returns [Error::OutOfRange] if the group is empty or an input of the
+    /// group has no witness.
+    pub fn signing_message(&self, group_input_indices: &[usize]) -> Result<H256, Error> {
+        let witness = |index: usize| {
+            self.witnesses.get(index).ok_or(Error::OutOfRange {
+                index,
+                len: self.witnesses.len(),
+            })
+        };
+        let (first, others) = group_input_indices
+            .split_first()
+            .ok_or(Error::OutOfRange { index: 0, len: 0 })?;
+
+        let mut first_witness = WitnessArgs::from_molecule_bytes(witness(*first)?)?;
+        if let Some(lock) = &mut first_witness.lock {
+            *lock = vec![0u8; lock.0.len()].into();
+        }
+        let first_witness = first_witness.to_molecule_bytes();
+
+        let mut blake2b = ckb_hash::new_blake2b();
+        blake2b.update(&self.calc_tx_hash().0);
+        blake2b.update(&(first_witness.len() as u64).to_le_bytes());
+        blake2b.update(&first_witness);
+
+        let trailing = self.witnesses.get(self.inputs.len()..).unwrap_or_default();
+        for witness in others
+            .iter()
+            .map(|index| witness(*index))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .chain(trailing)
+        {
+            blake2b.update(&(witness.len() as u64).to_le_bytes());
+            blake2b.update(witness);
+        }
+
+        let mut message = [0u8; 32];
+        blake2b.finalize(&mut message);
+        Ok(H256::new(message))
+    }
+
+    /// The proposal short id of the transaction computed from the stored
+    /// `hash`.
+    pub fn proposal_short_id(&self) -> ProposalShortId {
+        ProposalShortId::from_tx_hash(&self.hash)
+    }
+
+    /// Start building a transaction field by field, like
+    /// `packed::Transaction::new_builder()`.
+    pub fn new_builder() -> TransactionViewBuilder {
+        TransactionViewBuilder::default()
+    }
+
+    /// Replace the stored `hash` with the one computed from the current
+    /// fields.
+    ///
+    /// Call this after editing the public fields, which leaves `hash` stale.
+    pub fn with_recomputed_hash(mut self) -> Self {
+        self.hash = self.calc_tx_hash();
+        self
+    }
+
+    /// Whether `other` is the same transaction, comparing the hashes
+    /// recomputed from the fields.
+    ///
+    /// Unlike the derived `PartialEq`, which also compares the stored `hash`,
+    /// this ignores a stale `hash` on either side.
+    pub fn same_tx(&self, other: &Self) -> bool {
+        self.calc_tx_hash() == other.calc_tx_hash()
+    }
+
+    /// All the out points the transaction references, which are the previous
+    /// outputs of `inputs` followed by the out points of `cell_deps`, without
+    /// duplicates.
+    pub fn referenced_out_points(&self) -> Vec<OutPoint> {
+        let mut out_points = self
+            .inputs
+            .iter()
+            .map(|input| input.previous_output.clone())
+            .chain(self.cell_deps.iter().map(|dep| dep.out_point.clone()))
+            .collect();
+        dedup_out_points(&mut out_points);
+        out_points
+    }
+
+    /// Like [referenced_out_points](#method.referenced_out_points), but each
+    /// dep group found in `dep_group_data` is followed by its members parsed
+    /// from the group cell data.
+    ///
+    /// Dep groups missing from `dep_group_data` are not expanded.
+    pub fn referenced_out_points_expanded(
+        &self,
+        dep_group_data: &HashMap<OutPoint, GraphqlBytes>,
+    ) -> Result<Vec<OutPoint>, Error> {
+        let mut out_points = self
+            .inputs
+            .iter()
+            .map(|input| input.previous_output.clone())
+            .collect::<Vec<_>>();
+
+        for dep in self.cell_deps.iter() {
+            out_points.push(dep.out_point.clone());
+
+            if dep.dep_type == DepType::DepGroup {
+                if let Some(data) = dep_group_data.get(&dep.out_point) {
+                    out_points.extend(parse_dep_group(data)?);
+                }
+            }
+        }
+
+        dedup_out_points(&mut out_points);
+        Ok(out_points)
+    }
+
+    /// Pair each output with its data.
+    ///
+    /// The output `i` is paired with `outputs_data[i]`. If `outputs_data` is
+    /// shorter than `outputs`, the remaining outputs are paired with empty
+    /// data. Extra `outputs_data` items are ignored.
+    pub fn outputs_with_data(&self) -> Vec<CellOutputWithData> {
+        self.outputs
+            .iter()
+            .enumerate()
+            .map(|(i, output)| CellOutputWithData {
+                output: output.clone(),
+                data:   self.outputs_data.get(i).cloned().unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// The indices of the outputs whose capacity is at least `min`.
+    pub fn outputs_above(&self, min: Capacity) -> Vec<usize> {
+        self.outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, output)| output.capacity >= min)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The total capacity of the outputs locked by one of `burn_locks`, which
+    /// can never be spent. Saturates at `u64::MAX` on overflow.
+    pub fn burned_capacity(&self, burn_locks: &[Script]) -> Capacity {
+        self.outputs
+            .iter()
+            .filter(|output| burn_locks.contains(&output.lock))
+            .fold(Capacity::default(), |total, output| {
+                capacity_checked_add(&total, &output.capacity)
+                    .unwrap_or(Capacity::new(u64::MAX))
+            })
+    }
+
+    /// The total capacity of the outputs, or [Error::CapacityOverflow] if it
+    /// overflows `u64`.
+    pub fn total_output_capacity(&self) -> Result<Capacity, Error> {
+        sum_capacities(self.outputs.iter().map(|output| &output.capacity))
+    }
+
+    /// The transaction fee, which is the total capacity of the inputs minus
+    /// the total capacity of the outputs.
+    ///
+    /// The view doesn't carry the input capacities, so they are passed in
+    /// `input_capacities` with `input_capacities[i]` for `inputs[i]`.
+    pub fn fee(&self, input_capacities: &[Capacity]) -> Result<Capacity, Error> {
+        if input_capacities.len() != self.inputs.len() {
+            return Err(Error::InputCapacitiesMismatch {
+                expected: self.inputs.len(),
+                actual:   input_capacities.len(),
+            });
+        }
+
+        let inputs = sum_capacities(input_capacities.iter())?;
+        let outputs = self.total_output_capacity()?;
+
+        capacity_checked_sub(&inputs, &outputs).ok_or(Error::InsufficientInputCapacity {
+            inputs:  inputs.0,
+            outputs: outputs.0,
+        })
+    }
+
+    /// Collect the witnesses of each lock group, where `groups[i]` lists the
+    /// input indices locked by the group `i`.
+    ///
+    /// This follows the convention of the secp256k1 sighash-all lock: a group
+    /// owns the witnesses at the same indices as its inputs, and the trailing
+    /// witnesses beyond the number of inputs are not bound to any input, so
+    /// they are appended to every group. Indices without a witness are
+    /// skipped.
+    pub fn group_witnesses(&self, groups: &[Vec<usize>]) -> Vec<Vec<GraphqlBytes>> {
+        let trailing = self.witnesses.get(self.inputs.len()..).unwrap_or_default();
+
+        groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .filter_map(|i| self.witnesses.get(*i))
+                    .chain(trailing)
+                    .cloned()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Whether the transaction only moves capacity, which means no output has
+    /// a type script and all the outputs data are empty.
+    pub fn is_pure_transfer(&self) -> bool {
+        self.outputs.iter().all(|output| output.type_.is_none())
+            && self.outputs_data.iter().all(GraphqlBytes::is_empty)
+    }
+
+    /// The number of cells consumed and created, which is `(inputs.len(),
+    /// outputs.len())`.
+    pub fn cell_balance(&self) -> (usize, usize) {
+        (self.inputs.len(), self.outputs.len())
+    }
+
+    /// The net number of live cells added by the transaction, which is
+    /// negative if it consumes more cells than it creates.
+    pub fn net_cell_delta(&self) -> i64 {
+        self.outputs.len() as i64 - self.inputs.len() as i64
+    }
+
+    /// Compare the outputs with `other`, such as a transaction replacing this
+    /// one in a reorg.
+    ///
+    /// Returns the outputs only in `self` and the outputs only in `other`,
+    /// each in their original order.
+    pub fn output_diff(&self, other: &TransactionView) -> (Vec<CellOutput>, Vec<CellOutput>) {
+        let only_in = |a: &[CellOutput], b: &[CellOutput]| {
+            a.iter()
+                .filter(|output| !b.contains(output))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        (
+            only_in(&self.outputs, &other.outputs),
+            only_in(&other.outputs, &self.outputs),
+        )
+    }
+
+    /// Count the outputs by the order of magnitude of their capacity in CKB,
+    /// which is `floor(log10(capacity_in_ckb))`.
+    ///
+    /// Outputs with less than 1 CKB, including zero capacity ones, are counted
+    /// in bucket 0 together with the outputs under 10 CKB.
+    pub fn capacity_histogram(&self) -> BTreeMap<u32, usize> {
+        let mut histogram = BTreeMap::new();
+
+        for output in self.outputs.iter() {
+            let ckb = output.capacity.0 / BYTE_SHANNONS;
+            let bucket = if ckb == 0 { 0 } else { ckb.ilog10() };
+            *histogram.entry(bucket).or_insert(0) += 1;
+        }
+
+        histogram
+    }
+
+    /// Collect the distinct secp256k1 blake160 lock args among `locks`, in
+    /// the order they first appear.
+    ///
+    /// The transaction does not store the input cells, so the caller passes
+    /// the lock scripts of the resolved inputs. Other locks are skipped.
+    pub fn secp256k1_signer_hashes(locks: &[Script], network: Network) -> Vec<H160> {
+        let mut seen = HashSet::new();
+
+        locks
+            .iter()
+            .filter(|lock| lock.is_secp256k1_blake160(network))
+            .filter_map(Script::args_as_h160)
+            .filter(|hash| seen.insert(hash.clone()))
+            .collect()
+    }
+}
+
+/// The `hash` is computed from the generated fields rather than generated.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TransactionView {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            version:      u.arbitrary()?,
+            cell_deps:    u.arbitrary()?,
+            header_deps:  u.arbitrary()?,
+            inputs:       u.arbitrary()?,
+            outputs:      u.arbitrary()?,
+            outputs_data: u.arbitrary()?,
+            witnesses:    u.arbitrary()?,
+            hash:         H256::default(),
+        }
+        .with_recomputed_hash())
+    }
+}
+
+/// The builder of [TransactionView], created by
+/// [TransactionView::new_builder].
+///
+/// Each method appends one item, and [build](#method.build) fills in the
+/// `hash`.
+#[derive(Default, Clone, Debug)]
+pub struct TransactionViewBuilder {
+    inner: TransactionView,
+}
+
+impl TransactionViewBuilder {
+    /// Set the transaction version.
+    pub fn version(mut self, version: Version) -> Self {
+        self.inner.version = version;
+        self
+    }
+
+    /// Append a cell dep.
+    pub fn cell_dep(mut self, cell_dep: CellDep) -> Self {
+        self.inner.cell_deps.push(cell_dep);
+        self
+    }
+
+    /// Append a header dep.
+    pub fn header_dep(mut self, header_dep: H256) -> Self {
+        self.inner.header_deps.push(header_dep);
+        self
+    }
+
+    /// Append an input.
+    pub fn input(mut self, input: CellInput) -> Self {
+        self.inner.inputs.push(input);
+        self
+    }
+
+    /// Append an output, whose data is appended by [output_data](#method.output_data).
+    pub fn output(mut self, output: CellOutput) -> Self {
+        self.inner.outputs.push(output);
+        self
+    }
+
+    /// Append the data of an output.
+    pub fn output_data(mut self, data: GraphqlBytes) -> Self {
+        self.inner.outputs_data.push(data);
+        self
+    }
+
+    /// Append a witness.
+    pub fn witness(mut self, witness: GraphqlBytes) -> Self {
+        self.inner.witnesses.push(witness);
+        self
+    }
+
+    /// The hash of the transaction built so far.
+    ///
+    /// The hash is recomputed from scratch, so the cost grows with the
+    /// serialized size. Each append shifts the offset header of the molecule
+    /// `RawTransaction`, which changes the hashed bytes from the start, so the
+    /// blake2b state of an earlier call cannot be reused.
+    pub fn hash_so_far(&self) -> H256 {
+        self.inner.calc_tx_hash()
+    }
+
+    /// Finish the transaction with the computed `hash`.
+    pub fn build(self) -> TransactionView {
+        self.inner.with_recomputed_hash()
+    }
+}
+
+/// Remove the transactions that are the [same](TransactionView::same_tx) as an
+/// earlier one, keeping the first occurrence of each in the original order.
+///
+/// Witnesses are not part of the transaction hash, so of two transactions
+/// differing only in witnesses, the first is kept.
+pub fn dedup_transactions(transactions: &mut Vec<TransactionView>) {
+    let mut seen = HashSet::new();
+    transactions.retain(|tx| seen.insert(tx.calc_tx_hash()));
+}
+
+fn sum_capacities<'a>(
+    mut capacities: impl Iterator<Item = &'a Capacity>,
+) -> Result<Capacity, Error> {
+    capacities.try_fold(Capacity::default(), |total, capacity| {
+        capacity_checked_add(&total, capacity).ok_or(Error::CapacityOverflow)
+    })
+}
+
+#[cfg(test)]
+impl TransactionView {
+    pub fn random() -> Self {
+        let outputs = (0..3).map(|_| CellOutput::random()).collect::<Vec<_>>();
+        let tx = Self {
+            version: Version::random(),
+            cell_deps: (0..2).map(|_| CellDep::random()).collect(),
+            header_deps: (0..2).map(|_| H256::random()).collect(),
+            inputs: (0..2).map(|_| CellInput::random()).collect(),
+            outputs_data: outputs.iter().map(|_| GraphqlBytes::random()).collect(),
+            outputs,
+            witnesses: (0..2).map(|_| GraphqlBytes::random()).collect(),
+            hash: H256::default(),
+        };
+        tx.with_recomputed_hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_round_trip() {
+        for _ in 0..10 {
+            let view = TransactionView::random();
+            let tx = packed::Transaction::from(view.clone());
+
+            assert_eq!(TransactionView::from(tx.clone()), view);
+            assert_eq!(TransactionView::from(tx.clone().into_view()), view);
+            assert_eq!(
+                packed::Transaction::from(TransactionView::from(tx.clone())).as_slice(),
+                tx.as_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_core_view() {
+        let view = TransactionView::random();
+        let unsigned = core::TransactionBuilder::default()
+            .set_cell_deps(view.cell_deps.iter().cloned().map(Into::into).collect())
+            .set_inputs(view.inputs.iter().cloned().map(Into::into).collect())
+            .set_outputs(view.outputs.iter().cloned().map(Into::into).collect())
+            .set_outputs_data(view.outputs_data.iter().map(|data| data.0.pack()).collect())
+            .build();
+        let witnesses = view.witnesses.iter().map(|witness| witness.0.pack()).collect();
+        let signed = unsigned.as_advanced_builder().set_witnesses(witnesses).build();
+
+        let converted = TransactionView::from(signed.clone());
+        assert_eq!(converted.hash, H256::from(signed.hash()));
+        assert_eq!(converted.calc_tx_hash(), converted.hash);
+        assert_eq!(converted.witnesses, view.witnesses);
+        assert_eq!(
+            packed::Transaction::from(converted).as_slice(),
+            signed.data().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_from_packed_ref() {
+        let view = TransactionView::random();
+        let tx = packed::Transaction::from(view.clone());
+        let lock = tx.raw().outputs().get(0).unwrap().lock();
+
+        assert_eq!(TransactionView::from(&tx), view);
+        assert_eq!(Script::from(&lock), view.outputs[0].lock);
+        // The borrowed packed values are still usable.
+        assert_eq!(H256::from(tx.calc_tx_hash()), view.hash);
+        assert_eq!(lock.args().raw_data(), view.outputs[0].lock.args.0);
+    }
+
+    #[test]
+    fn test_stale_hash_is_ignored() {
+        let view = TransactionView::random();
+        let mut stale = view.clone();
+        stale.hash = H256::random();
+
+        let tx = packed::Transaction::from(stale);
+        assert_eq!(H256::from(tx.calc_tx_hash()), view.hash);
+        assert_eq!(TransactionView::from(tx), view);
+    }
+
+    #[test]
+    fn test_same_tx() {
+        let view = TransactionView::random();
+        let mut stale = view.clone();
+        stale.hash = H256::random();
+
+        assert_ne!(view, stale);
+        assert!(view.same_tx(&stale));
+        assert!(!view.same_tx(&TransactionView::random()));
+
+        let other = TransactionView::random();
+        let mut transactions = vec![view.clone(), stale, other.clone(), view.clone()];
+        dedup_transactions(&mut transactions);
+        assert_eq!(transactions, vec![view, other]);
+    }
+
+    #[test]
+    fn test_check_version() {
+        let mut view = TransactionView::random();
+
+        view.version = Version::new(0);
+        assert!(view.check_version().is_ok());
+
+        view.version = Version::new(1);
+        assert!(matches!(
+            view.check_version(),
+            Err(Error::UnsupportedVersion(1))
+        ));
+    }
+
+    #[test]
+    fn test_check_distinct_header_deps() {
+        let mut view = TransactionView::random();
+        assert!(view.check_distinct_header_deps().is_ok());
+
+        let duplicate = view.header_deps[0].clone();
+        view.header_deps.push(H256::random());
+        view.header_deps.push(duplicate.clone());
+        assert!(matches!(
+            view.check_distinct_header_deps(),
+            Err(Error::DuplicateHeaderDep(hash)) if hash == duplicate
+        ));
+    }
+
+    #[test]
+    fn test_check_first_witness_lock_len() {
+        let with_lock = |len: usize| {
+            let args = WitnessArgs {
+                lock: Some(vec![0u8; len].into()),
+                ..Default::default()
+            };
+            TransactionView {
+                witnesses: vec![args.to_molecule_bytes().into()],
+                ..Default::default()
+            }
+        };
+
+        assert!(with_lock(65).check_first_witness_lock_len(65).is_ok());
+        assert!(matches!(
+            with_lock(64).check_first_witness_lock_len(65),
+            Err(Error::WrongLockLength {
+                expected: 65,
+                actual:   64,
+            })
+        ));
+        assert!(matches!(
+            TransactionView::default().check_first_witness_lock_len(65),
+            Err(Error::WrongLockLength { actual: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_molecule_bytes() {
+        let view = TransactionView {
+            outputs: vec![CellOutput::random()],
+            outputs_data: vec![GraphqlBytes::default()],
+            ..Default::default()
+        }
+        .with_recomputed_hash();
+        let bytes = view.to_molecule_bytes();
+
+        assert_eq!(TransactionView::from_molecule_bytes(&bytes).unwrap(), view);
+        assert!(matches!(
+            TransactionView::from_molecule_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::Molecule(_))
+        ));
+    }
+
+    #[test]
+    fn test_raw_transaction_view() {
+        let view = TransactionView::random();
+        let raw = packed::RawTransaction::from(view.raw());
+
+        assert_eq!(H256::from(raw.calc_tx_hash()), view.hash);
+        assert_eq!(raw.as_slice(), &view.raw_molecule_bytes()[..]);
+        assert_eq!(RawTransactionView::from(raw), view.raw());
+    }
+
+    #[test]
+    fn test_raw_molecule_bytes() {
+        let view = TransactionView::random();
+        let raw = packed::RawTransaction::from_slice(&view.raw_molecule_bytes()).unwrap();
+
+        assert_eq!(H256::from(raw.calc_tx_hash()), view.hash);
+        assert_ne!(view.raw_molecule_bytes(), view.to_molecule_bytes());
+    }
+
+    /// A testnet transfer of the genesis cellbase output 7 to the owner of
+    /// output 8, both secp256k1 blake160 sighash-all cells, with a 65-byte
+    /// signature placeholder in the first witness.
+    fn secp256k1_fixture() -> TransactionView {
+        let lock = Script {
+            code_hash: crate::SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH,
+            hash_type: crate::ScriptHashType::Type,
+            args:      "0x3f1573b44218d4c12a91919a58a863be415a2bc3".parse().unwrap(),
+        };
+        let witness = WitnessArgs {
+            lock: Some(vec![0x33; 65].into()),
+            ..Default::default()
+        };
+
+        TransactionView {
+            cell_deps: vec![crate::TESTNET_SECP256K1_BLAKE160_DEP],
+            inputs: vec![CellInput {
+                previous_output: crate::OutPoint {
+                    tx_hash: "0x8f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f"
+                        .parse()
+                        .unwrap(),
+                    index:   crate::Uint32::new(7),
+                },
+                ..Default::default()
+            }],
+            outputs: vec![CellOutput {
+                capacity: Capacity::new(839_957_834_499_900_000),
+                lock,
+                type_: None,
+            }],
+            outputs_data: vec![GraphqlBytes::default()],
+            witnesses: vec![witness.to_molecule_bytes().into()],
+            ..Default::default()
+        }
+        .with_recomputed_hash()
+    }
+
+    #[test]
+    fn test_signing_message() {
+        let mut view = secp256k1_fixture();
+
+        assert_eq!(
+            view.hash,
+            "0xb1abbca5263dbd147965d61bcbf8cfb54ef55e1a79eeb4bf4b6ab51c756eaf0a"
+                .parse::<H256>()
+                .unwrap()
+        );
+        assert_eq!(
+            view.signing_message(&[0]).unwrap(),
+            "0x5451b5a35e184e72162cdf34a4157e9182b584df85817cf2f184536a4373bed1"
+                .parse::<H256>()
+                .unwrap()
+        );
+        assert!(matches!(
+            view.signing_message(&[0, 1]),
+            Err(Error::OutOfRange { index: 1, len: 1 })
+        ));
+        assert!(view.signing_message(&[]).is_err());
+
+        let mut stale = view.clone();
+        stale.hash = H256::random();
+        assert_eq!(
+            stale.signing_message(&[0]).unwrap(),
+            view.signing_message(&[0]).unwrap()
+        );
+
+        // An absent lock is hashed as absent, not as a zero-length lock.
+        view.witnesses[0] = WitnessArgs::default().to_molecule_bytes().into();
+        assert_eq!(
+            view.signing_message(&[0]).unwrap(),
+            "0x1cc52bfa672c6c7e8041427ba822293ef806e808d1d8ed7a60a28eb029edc2de"
+                .parse::<H256>()
+                .unwrap()
+        );
+    }
+
+    /// The testnet genesis transaction
+    /// `0xf8de3bb47d055cdf460d93a2a6e1b05f7432f9777c8c474abf4eec1d4aee5d37`
+    /// creating the secp256k1 dep groups.
+    fn testnet_dep_group_tx() -> TransactionView {
+        let cellbase_tx_hash: H256 =
+            "0x8f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f"
+                .parse()
+                .unwrap();
+        let out_point = |index| crate::OutPoint {
+            tx_hash: cellbase_tx_hash.clone(),
+            index:   crate::Uint32::new(index),
+        };
+        let output = CellOutput {
+            capacity: Capacity::new(0x2b95fd500),
+            lock:     Script::default(),
+            type_:    None,
+        };
+
+        TransactionView {
+            cell_deps: [3, 1]
+                .into_iter()
+                .map(|index| crate::CellDep {
+                    out_point: out_point(index),
+                    dep_type:  crate::DepType::Code,
+                })
+                .collect(),
+            inputs: vec![CellInput {
+                previous_output: out_point(5),
+                ..Default::default()
+            }],
+            outputs: vec![output.clone(), output],
+            outputs_data: [
+                "0x020000008f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f\
+                 030000008f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f01000000",
+                "0x020000008f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f\
+                 030000008f8c79eb6671709633fe6a46de93c0fedc9c1b8a6527a18d3983879542635c9f04000000",
+            ]
+            .into_iter()
+            .map(|data| data.parse().unwrap())
+            .collect(),
+            witnesses: vec!["0x55f49d7979ba246aa2f05a6e9afd25a23dc39ed9085a0b1e33b6b3bb80d34dbd\
+                             4031a04ea389d6d8ff5604828889aa06a827e930a7e89411b80f6c3e1404951f00"
+                .parse()
+                .unwrap()],
+            ..Default::default()
+        }
+        .with_recomputed_hash()
+    }
+
+    #[test]
+    fn test_calc_witness_hash() {
+        let view = testnet_dep_group_tx();
+
+        assert_eq!(
+            view.hash,
+            "0xf8de3bb47d055cdf460d93a2a6e1b05f7432f9777c8c474abf4eec1d4aee5d37"
+                .parse::<H256>()
+                .unwrap()
+        );
+        // Committed by the testnet genesis `transactions_root`.
+        assert_eq!(
+            view.calc_witness_hash(),
+            "0xab266d8849b051c781265ad99e4e21340542de0887f2849be5d8a63ddf35d7d1"
+                .parse::<H256>()
+                .unwrap()
+        );
+
+        let mut other = view.clone();
+        other.witnesses.push(GraphqlBytes::random());
+        assert_eq!(other.calc_tx_hash(), view.hash);
+        assert_ne!(other.calc_witness_hash(), view.calc_witness_hash());
+    }
+
+    #[test]
+    fn test_proposal_short_id() {
+        let view = TransactionView::random();
+        let id = view.proposal_short_id();
+
+        assert_eq!(id.0, view.hash.0[..10]);
+        assert_eq!(
+            id,
+            ProposalShortId::from(packed::Transaction::from(view).proposal_short_id())
+        );
+    }
+
+    #[test]
+    fn test_calc_hash_with() {
+        let view = TransactionView::random();
+        let received = std::cell::RefCell::new(Vec::new());
+        let hash = view.calc_hash_with(|bytes| {
+            received.borrow_mut().extend_from_slice(bytes);
+            [7u8; 32]
+        });
+
+        assert_eq!(hash, H256::new([7u8; 32]));
+        assert_eq!(received.into_inner(), view.raw_molecule_bytes());
+    }
+
+    #[test]
+    fn test_from_raw_and_witnesses() {
+        let view = TransactionView::random();
+        let tx = packed::Transaction::from(view.clone());
+        let witnesses = tx
+            .witnesses()
+            .into_iter()
+            .map(|witness| witness.raw_data().to_vec())
+            .collect::<Vec<_>>();
+        let witnesses = witnesses.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        assert_eq!(
+            TransactionView::from_raw_and_witnesses(tx.raw().as_slice(), &witnesses).unwrap(),
+            view
+        );
+        assert!(matches!(
+            TransactionView::from_raw_and_witnesses(&[0u8; 3], &witnesses),
+            Err(Error::Molecule(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_recomputed_hash() {
+        let mut view = TransactionView::random();
+        let stale = view.hash.clone();
+        view.outputs[0].capacity = Capacity::new(view.outputs[0].capacity.0.wrapping_add(1));
+
+        let view = view.with_recomputed_hash();
+        assert_ne!(view.hash, stale);
+        assert_eq!(
+            view.hash,
+            H256::from(packed::Transaction::from(view.clone()).calc_tx_hash())
+        );
+    }
+
+    #[test]
+    fn test_outputs_with_data() {
+        let mut view = TransactionView::random();
+        let pairs = view.outputs_with_data();
+
+        assert_eq!(pairs.len(), view.outputs.len());
+        for (i, pair) in pairs.iter().enumerate() {
+            assert_eq!(pair.output, view.outputs[i]);
+            assert_eq!(pair.data, view.outputs_data[i]);
+        }
+
+        view.outputs_data.truncate(1);
+        let pairs = view.outputs_with_data();
+
+        assert_eq!(pairs.len(), view.outputs.len());
+        assert_eq!(pairs[0].data, view.outputs_data[0]);
+        assert!(pairs[1..].iter().all(|pair| pair.data.0.is_empty()));
+    }
+
+    #[test]
+    fn test_outputs_above() {
+        let mut view = TransactionView::random();
+        view.outputs = [100, 61, 62, 60, 61]
+            .into_iter()
+            .map(|ckb| CellOutput {
+                capacity: Capacity::new(ckb * BYTE_SHANNONS),
+                ..CellOutput::random()
+            })
+            .collect();
+
+        assert_eq!(view.outputs_above(Capacity::new(61 * BYTE_SHANNONS)), vec![
+            0, 1, 2, 4
+        ]);
+    }
+
+    #[test]
+    fn test_burned_capacity() {
+        let burn_lock = Script::default();
+        let mut view = TransactionView::random();
+        view.outputs.truncate(2);
+        view.outputs[0].lock = burn_lock.clone();
+        view.outputs[0].capacity = Capacity::new(100 * BYTE_SHANNONS);
+        view.outputs[1].capacity = Capacity::new(200 * BYTE_SHANNONS);
+
+        assert_eq!(
+            view.burned_capacity(std::slice::from_ref(&burn_lock)),
+            Capacity::new(100 * BYTE_SHANNONS)
+        );
+        assert_eq!(view.burned_capacity(&[]), Capacity::default());
+
+        view.outputs[1].lock = burn_lock.clone();
+        view.outputs[1].capacity = Capacity::new(u64::MAX);
+        assert_eq!(view.burned_capacity(&[burn_lock]), Capacity::new(u64::MAX));
+    }
+
+    #[test]
+    fn test_total_output_capacity() {
+        let mut view = TransactionView::default();
+        assert_eq!(view.total_output_capacity().unwrap(), Capacity::new(0));
+
+        view.outputs = vec![CellOutput::random(), CellOutput::random()];
+        view.outputs[0].capacity = Capacity::new(61 * BYTE_SHANNONS);
+        view.outputs[1].capacity = Capacity::new(100 * BYTE_SHANNONS);
+        assert_eq!(
+            view.total_output_capacity().unwrap(),
+            Capacity::new(161 * BYTE_SHANNONS)
+        );
+
+        view.outputs[0].capacity = Capacity::new(u64::MAX);
+        assert!(matches!(
+            view.total_output_capacity(),
+            Err(Error::CapacityOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_fee() {
+        let view = TransactionView::random();
+        let mut view = TransactionView {
+            outputs: view
+                .outputs
+                .into_iter()
+                .map(|output| CellOutput {
+                    capacity: Capacity::new(100),
+                    ..output
+                })
+                .collect(),
+            ..view
+        };
+
+        let fee = view.fee(&[Capacity::new(200), Capacity::new(150)]);
+        assert_eq!(fee.unwrap(), Capacity::new(50));
+        let fee = view.fee(&[Capacity::new(200), Capacity::new(100)]);
+        assert_eq!(fee.unwrap(), Capacity::new(0));
+        assert!(matches!(
+            view.fee(&[Capacity::new(200), Capacity::new(99)]),
+            Err(Error::InsufficientInputCapacity {
+                inputs:  299,
+                outputs: 300,
+            })
+        ));
+        assert!(matches!(
+            view.fee(&[Capacity::new(300)]),
+            Err(Error::InputCapacitiesMismatch {
+                expected: 2,
+                actual:   1,
+            })
+        ));
+
+        view.outputs[0].capacity = Capacity::new(u64::MAX);
+        assert!(matches!(
+            view.fee(&[Capacity::new(200), Capacity::new(100)]),
+            Err(Error::CapacityOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_group_witnesses() {
+        let mut view = TransactionView::random();
+        view.inputs.push(CellInput::random());
+        view.witnesses = (0..4).map(|_| GraphqlBytes::random()).collect();
+        let w = view.witnesses.clone();
+
+        assert_eq!(view.group_witnesses(&[vec![0, 2], vec![1]]), vec![
+            vec![w[0].clone(), w[2].clone(), w[3].clone()],
+            vec![w[1].clone(), w[3].clone()],
+        ]);
+
+        view.witnesses.truncate(2);
+        assert_eq!(view.group_witnesses(&[vec![0, 2], vec![1]]), vec![
+            vec![w[0].clone()],
+            vec![w[1].clone()],
+        ]);
+    }
+
+    #[test]
+    fn test_is_pure_transfer() {
+        let mut view = TransactionView::random();
+        view.outputs
+            .iter_mut()
+            .for_each(|output| output.type_ = None);
+        view.outputs_data = vec![GraphqlBytes::default(); view.outputs.len()];
+        assert!(view.is_pure_transfer());
+
+        view.outputs[1].type_ = Some(Script::random());
+        assert!(!view.is_pure_transfer());
+
+        view.outputs[1].type_ = None;
+        view.outputs_data[2] = GraphqlBytes::random();
+        assert!(!view.is_pure_transfer());
+    }
+
+    #[test]
+    fn test_cell_balance() {
+        let mut view = TransactionView::random();
+        assert_eq!(view.cell_balance(), (2, 3));
+        assert_eq!(view.net_cell_delta(), 1);
+
+        view.outputs.truncate(1);
+        assert_eq!(view.cell_balance(), (2, 1));
+        assert_eq!(view.net_cell_delta(), -1);
+    }
+
+    #[test]
+    fn test_output_diff() {
+        let (shared, mine, theirs) = (
+            CellOutput::random(),
+            CellOutput::random(),
+            CellOutput::random(),
+        );
+        let view = TransactionView {
+            outputs: vec![mine.clone(), shared.clone()],
+            ..Default::default()
+        };
+        let other = TransactionView {
+            outputs: vec![shared, theirs.clone()],
+            ..Default::default()
+        };
+
+        assert_eq!(view.output_diff(&other), (vec![mine], vec![theirs]));
+        assert_eq!(view.output_diff(&view), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_capacity_histogram() {
+        let mut view = TransactionView::random();
+        view.outputs = [0, 5, 61, 99, 1_000, 20_000]
+            .into_iter()
+            .map(|ckb| CellOutput {
+                capacity: Capacity::new(ckb * BYTE_SHANNONS),
+                ..CellOutput::random()
+            })
+            .collect();
+
+        assert_eq!(
+            view.capacity_histogram(),
+            BTreeMap::from([(0, 2), (1, 2), (3, 1), (4, 1)])
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_signer_hashes() {
+        let network = Network::Mainnet;
+        let secp256k1 = |args: GraphqlBytes| Script {
+            code_hash: network.secp256k1_blake160_code_hash(),
+            hash_type: crate::ScriptHashType::Type,
+            args,
+        };
+        let a = H160::random();
+        let b = H160::random();
+        let locks = vec![
+            secp256k1(a.0.to_vec().into()),
+            Script::random(),
+            secp256k1(b.0.to_vec().into()),
+            secp256k1(a.0.to_vec().into()),
+            secp256k1(vec![0u8; 21].into()),
+        ];
+
+        assert_eq!(
+            TransactionView::secp256k1_signer_hashes(&locks, network),
+            vec![a, b]
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..4096).map(|_| rand::random::<u8>()).collect::<Vec<_>>();
+        let view = TransactionView::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        assert_eq!(view.hash, view.calc_tx_hash());
+        assert_eq!(
+            TransactionView::from_molecule_bytes(&view.to_molecule_bytes()).unwrap(),
+            view
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let cell_dep = CellDep::random();
+        let header_dep = H256::random();
+        let input = CellInput::random();
+        let output = CellOutput::random();
+        let data = GraphqlBytes::random();
+        let witness = GraphqlBytes::random();
+
+        let tx = TransactionView::new_builder()
+            .version(Version::new(0))
+            .cell_dep(cell_dep.clone())
+            .header_dep(header_dep.clone())
+            .input(input.clone())
+            .output(output.clone())
+            .output_data(data.clone())
+            .witness(witness.clone())
+            .build();
+
+        assert_eq!(tx.cell_deps, vec![cell_dep]);
+        assert_eq!(tx.header_deps, vec![header_dep]);
+        assert_eq!(tx.inputs, vec![input]);
+        assert_eq!(tx.outputs, vec![output]);
+        assert_eq!(tx.outputs_data, vec![data]);
+        assert_eq!(tx.witnesses, vec![witness]);
+        assert_eq!(
+            tx.hash,
+            H256::from(packed::Transaction::from(tx.clone()).calc_tx_hash())
+        );
+    }
+
+    #[test]
+    fn test_hash_so_far() {
+        let mut builder = TransactionView::new_builder();
+        for _ in 0..3 {
+            let before = builder.hash_so_far();
+            builder = builder
+                .input(CellInput::random())
+                .output(CellOutput::random())
+                .output_data(GraphqlBytes::random())
+                .witness(GraphqlBytes::random());
+            assert_ne!(builder.hash_so_far(), before);
+        }
+
+        let hash = builder.hash_so_far();
+        assert_eq!(builder.build().hash, hash);
+    }
+
+    #[test]
+    fn test_referenced_out_points() {
+        let shared = OutPoint::random();
+        let input_only = OutPoint::random();
+        let member = OutPoint::random();
+        let group = OutPoint::random();
+
+        let mut tx = TransactionView::random();
+        tx.inputs = [shared.clone(), input_only.clone()]
+            .into_iter()
+            .map(|previous_output| CellInput {
+                previous_output,
+                ..CellInput::random()
+            })
+            .collect();
+        tx.cell_deps = vec![
+            CellDep {
+                out_point: shared.clone(),
+                dep_type:  DepType::Code,
+            },
+            CellDep {
+                out_point: group.clone(),
+                dep_type:  DepType::DepGroup,
+            },
+        ];
+
+        assert_eq!(tx.referenced_out_points(), vec![
+            shared.clone(),
+            input_only.clone(),
+            group.clone()
+        ]);
+
+        let members = packed::OutPointVec::new_builder()
+            .push(member.clone().into())
+            .push(shared.clone().into())
+            .build();
+        let data = HashMap::from([(group.clone(), GraphqlBytes(members.as_bytes()))]);
+        assert_eq!(tx.referenced_out_points_expanded(&data).unwrap(), vec![
+            shared,
+            input_only,
+            group.clone(),
+            member
+        ]);
+
+        let malformed = HashMap::from([(group, GraphqlBytes::from(vec![1u8; 3]))]);
+        assert!(matches!(
+            tx.referenced_out_points_expanded(&malformed),
+            Err(Error::Molecule(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_structure() {
+        let tx = TransactionView::random();
+        tx.validate_structure().unwrap();
+        assert_eq!(
+            tx.clone().into_packed_checked().unwrap().as_slice(),
+            packed::Transaction::from(tx.clone()).as_slice()
+        );
+
+        let mut mismatched = tx.clone();
+        mismatched.outputs_data.push(GraphqlBytes::random());
+        assert!(matches!(
+            mismatched.into_packed_checked(),
+            Err(Error::OutputsDataMismatch {
+                outputs:      3,
+                outputs_data: 4,
+            })
+        ));
+
+        let mut no_inputs = tx.clone();
+        no_inputs.inputs.clear();
+        assert!(matches!(
+            no_inputs.validate_structure(),
+            Err(Error::StructureMismatch("no inputs"))
+        ));
+
+        let mut no_outputs = tx;
+        no_outputs.outputs.clear();
+        no_outputs.outputs_data.clear();
+        assert!(matches!(
+            no_outputs.validate_structure(),
+            Err(Error::StructureMismatch("no outputs"))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let tx = TransactionView::random();
+        let json = serde_json::to_string(&tx).unwrap();
+        assert_eq!(serde_json::from_str::<TransactionView>(&json).unwrap(), tx);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_checked() {
+        let mut tx = TransactionView::random();
+        let json = serde_json::to_string(&tx).unwrap();
+        assert_eq!(TransactionView::deserialize_checked(&json).unwrap(), tx);
+
+        tx.outputs_data.pop();
+        let json = serde_json::to_string(&tx).unwrap();
+        assert!(matches!(
+            TransactionView::deserialize_checked(&json),
+            Err(Error::OutputsDataMismatch {
+                outputs:      3,
+                outputs_data: 2,
+            })
+        ));
+        assert!(matches!(
+            TransactionView::deserialize_checked("{"),
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_pretty_json() {
+        let tx = TransactionView::random();
+        let json = tx.to_pretty_json();
+
+        assert!(json.contains("\n  \"version\": "));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::to_value(&tx).unwrap()
+        );
+        assert_eq!(serde_json::from_str::<TransactionView>(&json).unwrap(), tx);
+    }
+}
@@ -0,0 +1,110 @@
+use async_graphql::SimpleObject;
+use ckb_types::{packed, prelude::*};
+
+use crate::{error::Error, GraphqlBytes};
+
+/// The witness structure used by the default lock and type scripts.
+///
+/// Refer to RFC [CKB Transaction Structure](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0022-transaction-structure/0022-transaction-structure.md#witnesses).
+#[derive(SimpleObject, Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct WitnessArgs {
+    /// The witness of the lock script, such as a signature.
+    pub lock:        Option<GraphqlBytes>,
+    /// The witness of the type script when the cell is an input.
+    pub input_type:  Option<GraphqlBytes>,
+    /// The witness of the type script when the cell is an output.
+    pub output_type: Option<GraphqlBytes>,
+}
+
+impl From<packed::WitnessArgs> for WitnessArgs {
+    fn from(value: packed::WitnessArgs) -> Self {
+        let unpack = |bytes: packed::BytesOpt| bytes.to_opt().map(|b| GraphqlBytes(b.unpack()));
+
+        Self {
+            lock:        unpack(value.lock()),
+            input_type:  unpack(value.input_type()),
+            output_type: unpack(value.output_type()),
+        }
+    }
+}
+
+impl From<WitnessArgs> for packed::WitnessArgs {
+    fn from(value: WitnessArgs) -> Self {
+        let pack = |bytes: Option<GraphqlBytes>| {
+            packed::BytesOpt::new_builder()
+                .set(bytes.map(|b| b.0.pack()))
+                .build()
+        };
+
+        Self::new_builder()
+            .lock(pack(value.lock))
+            .input_type(pack(value.input_type))
+            .output_type(pack(value.output_type))
+            .build()
+    }
+}
+
+impl WitnessArgs {
+    /// Parse the witness args from a molecule encoded witness.
+    pub fn from_molecule_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(packed::WitnessArgs::from_slice(bytes)?.into())
+    }
+
+    /// Serialize the witness args in the molecule format.
+    pub fn to_molecule_bytes(&self) -> Vec<u8> {
+        packed::WitnessArgs::from(self.clone()).as_slice().to_vec()
+    }
+
+    /// The `output_type` field, where protocols such as RGB++ store their
+    /// commitments.
+    pub fn output_type_bytes(&self) -> Option<GraphqlBytes> {
+        self.output_type.clone()
+    }
+
+    /// The length of the `lock` field, or 0 if it is absent.
+    pub fn lock_len(&self) -> usize {
+        self.lock.as_ref().map_or(0, |lock| lock.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_witness_args_round_trip() {
+        let args = WitnessArgs {
+            lock:        Some(GraphqlBytes::random()),
+            input_type:  None,
+            output_type: Some(GraphqlBytes::default()),
+        };
+        let bytes = args.to_molecule_bytes();
+
+        assert_eq!(WitnessArgs::from_molecule_bytes(&bytes).unwrap(), args);
+        assert_eq!(args.lock_len(), 128);
+        assert_eq!(WitnessArgs::default().lock_len(), 0);
+        assert!(matches!(
+            WitnessArgs::from_molecule_bytes(&[0u8; 3]),
+            Err(Error::Molecule(_))
+        ));
+    }
+
+    #[test]
+    fn test_output_type_bytes() {
+        let commitment = GraphqlBytes::random();
+        let args = WitnessArgs {
+            output_type: Some(commitment.clone()),
+            ..Default::default()
+        };
+        let bytes = args.to_molecule_bytes();
+
+        assert_eq!(
+            WitnessArgs::from_molecule_bytes(&bytes)
+                .unwrap()
+                .output_type_bytes(),
+            Some(commitment)
+        );
+        assert_eq!(WitnessArgs::default().output_type_bytes(), None);
+    }
+}